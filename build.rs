@@ -0,0 +1,42 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+// game data is embedded like the rest of the assets, so a shipped binary doesn't need the folder,
+// the files found in the folder at runtime still take precedence
+fn main() {
+    println!("cargo:rerun-if-changed=assets");
+
+    let mut files = vec![];
+    collect(Path::new("assets"), &mut files);
+    files.sort();
+
+    let mut code = String::from("static FILES: &[(&str, &str)] = &[\n");
+    for path in files {
+        let name = path
+            .strip_prefix("assets")
+            .unwrap()
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let full = fs::canonicalize(&path).unwrap();
+        code += &format!("    ({name:?}, include_str!({full:?})),\n");
+    }
+    code += "];\n";
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("data_files.rs");
+    fs::write(out, code).unwrap();
+}
+
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "ron") {
+            files.push(path);
+        }
+    }
+}
@@ -1,12 +1,12 @@
-use rand::random;
+use rand::{rngs::StdRng, Rng};
 
 use crate::battle::{
-    effect::{Effect, HasEffect},
+    effect::{Effect, EffectSource, HasEffect},
     fight::Fighter,
-    modifier::{Modifier, ModifierDesc, Target, ValueKind},
+    modifier::{DamageType, Modifier, ModifierDesc, Target, ValueKind},
 };
 
-use super::Ability;
+use super::{Ability, AbilityDef};
 
 #[derive(Debug)]
 pub struct Attack {
@@ -22,18 +22,28 @@ impl HasEffect for Ability<Attack> {
         }
         .into()
     }
+
+    fn source(&self) -> EffectSource {
+        EffectSource::Ability(AbilityDef::Attack)
+    }
 }
 
 impl Effect for Attack {
-    fn update(&mut self, delta: f32, myself: &Fighter, enemy: &Fighter) -> Vec<ModifierDesc> {
+    fn update(
+        &mut self,
+        delta: f32,
+        myself: &Fighter,
+        _enemy: &Fighter,
+        rng: &mut StdRng,
+    ) -> Vec<ModifierDesc> {
         let mut modifiers = vec![];
         self.projectiles.retain_mut(|timer| {
             *timer += delta;
             if *timer >= 0.5 {
-                let crit = random::<f32>() <= myself.crit;
+                let crit = rng.gen::<f32>() <= myself.crit;
                 let damage = myself.attack * if crit { 2.5 } else { 1.0 };
                 modifiers.push(ModifierDesc {
-                    modifier: Modifier::AffectHP(-damage),
+                    modifier: Modifier::Damage(damage, DamageType::Physical),
                     target: Target::Enemy,
                     value_kind: ValueKind::Units,
                 });
@@ -49,6 +59,9 @@ impl Effect for Attack {
                 true
             }
         });
+        if myself.stunned() {
+            return modifiers;
+        }
         if self.timer >= 1.0 / myself.attack_speed {
             self.timer = 0.0;
             self.projectiles.push(0.0);
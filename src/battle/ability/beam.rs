@@ -1,36 +1,54 @@
+use rand::rngs::StdRng;
+
 use crate::battle::{
-    effect::{Effect, HasEffect},
+    effect::Effect,
     fight::Fighter,
-    modifier::{Modifier, ModifierDesc, Target, ValueKind},
+    modifier::{DamageType, Modifier, ModifierDesc, Target, ValueKind},
 };
 
-use super::Ability;
-
 #[derive(Debug)]
-pub struct Beam<const HEAL: bool, const VALUE: u32, const REDUCE_MANA: bool = true> {
+pub struct Beam {
+    heal: bool,
+    value: f32,
+    reduce_mana: bool,
     beams: Vec<f32>,
 }
 
-impl<const HEAL: bool, const VALUE: u32, const REDUCE_MANA: bool> HasEffect
-    for Ability<Beam<HEAL, VALUE, REDUCE_MANA>>
-{
-    fn effect(&self) -> Box<dyn Effect> {
-        Beam::<HEAL, VALUE> { beams: vec![] }.into()
+impl Beam {
+    pub fn new(heal: bool, value: f32, reduce_mana: bool) -> Self {
+        Self {
+            heal,
+            value,
+            reduce_mana,
+            beams: vec![],
+        }
     }
 }
 
-impl<const HEAL: bool, const VALUE: u32, const REDUCE_MANA: bool> Effect
-    for Beam<HEAL, VALUE, REDUCE_MANA>
-{
-    fn update(&mut self, delta: f32, myself: &Fighter, enemy: &Fighter) -> Vec<ModifierDesc> {
+impl Effect for Beam {
+    fn update(
+        &mut self,
+        delta: f32,
+        myself: &Fighter,
+        _enemy: &Fighter,
+        _rng: &mut StdRng,
+    ) -> Vec<ModifierDesc> {
         let mut modifiers = vec![];
 
         self.beams.retain_mut(|timer| {
             *timer += delta;
             if *timer >= 1.0 {
                 modifiers.push(ModifierDesc {
-                    modifier: Modifier::AffectHP(VALUE as f32 * if HEAL { 1.0 } else { -1.0 }),
-                    target: if HEAL { Target::Myself } else { Target::Enemy },
+                    modifier: if self.heal {
+                        Modifier::Heal(self.value)
+                    } else {
+                        Modifier::Damage(self.value, DamageType::Magical)
+                    },
+                    target: if self.heal {
+                        Target::Myself
+                    } else {
+                        Target::Enemy
+                    },
                     value_kind: ValueKind::Ulti,
                 });
                 false
@@ -39,10 +57,10 @@ impl<const HEAL: bool, const VALUE: u32, const REDUCE_MANA: bool> Effect
             }
         });
 
-        if myself.mana >= 100.0 {
+        if myself.ulti_ready() {
             self.beams.push(0.0);
             modifiers.push(ModifierDesc {
-                modifier: if HEAL {
+                modifier: if self.heal {
                     Modifier::ShootHealBeam
                 } else {
                     Modifier::ShootDamageBeam
@@ -55,7 +73,7 @@ impl<const HEAL: bool, const VALUE: u32, const REDUCE_MANA: bool> Effect
                 target: Target::Myself,
                 value_kind: ValueKind::Units,
             });
-            if REDUCE_MANA {
+            if self.reduce_mana {
                 modifiers.push(ModifierDesc {
                     modifier: Modifier::AffectMana(-100.0),
                     target: Target::Myself,
@@ -1,10 +1,12 @@
+use rand::rngs::StdRng;
+
 use crate::battle::{
-    effect::{Effect, HasEffect},
+    effect::{Effect, EffectSource, HasEffect},
     fight::Fighter,
-    modifier::{Modifier, ModifierDesc, Target, ValueKind},
+    modifier::{DamageType, Modifier, ModifierDesc, Target, ValueKind},
 };
 
-use super::Ability;
+use super::{Ability, AbilityDef};
 
 pub const CUBE_SIDE: u32 = 3;
 pub const CUBE_FIRES: u32 = CUBE_SIDE * CUBE_SIDE * CUBE_SIDE;
@@ -27,17 +29,27 @@ impl HasEffect for Ability<FireCube> {
         }
         .into()
     }
+
+    fn source(&self) -> EffectSource {
+        EffectSource::Ability(AbilityDef::FireCube)
+    }
 }
 
 impl Effect for FireCube {
-    fn update(&mut self, delta: f32, myself: &Fighter, enemy: &Fighter) -> Vec<ModifierDesc> {
+    fn update(
+        &mut self,
+        delta: f32,
+        myself: &Fighter,
+        _enemy: &Fighter,
+        _rng: &mut StdRng,
+    ) -> Vec<ModifierDesc> {
         let mut modifiers = vec![];
 
         self.projectiles.retain_mut(|timer| {
             *timer += delta;
             if *timer >= 0.5 {
                 modifiers.push(ModifierDesc {
-                    modifier: Modifier::AffectHP(-300.0 / CUBE_FIRES as f32),
+                    modifier: Modifier::Damage(300.0 / CUBE_FIRES as f32, DamageType::Magical),
                     target: Target::Enemy,
                     value_kind: ValueKind::Ulti,
                 });
@@ -72,7 +84,7 @@ impl Effect for FireCube {
             self.current += 1;
         }
 
-        if myself.mana >= 100.0 {
+        if myself.ulti_ready() {
             self.fires_ready.append(&mut self.fires_charging);
             modifiers.extend(vec![
                 ModifierDesc {
@@ -1,29 +1,39 @@
+use rand::rngs::StdRng;
+
 use crate::battle::{
-    effect::{Effect, HasEffect},
+    effect::{Effect, EffectSource, HasEffect},
     fight::Fighter,
-    modifier::{Modifier, ModifierDesc, Target, ValueKind},
+    modifier::{DamageType, Modifier, ModifierDesc, Target, ValueKind},
 };
 
-use super::Ability;
+use super::{Ability, AbilityDef};
 
 #[derive(Debug)]
 pub struct Halve {
-    timer: f32,
     projectiles: Vec<f32>,
 }
 
 impl HasEffect for Ability<Halve> {
     fn effect(&self) -> Box<dyn Effect> {
         Halve {
-            timer: 0.0,
             projectiles: vec![],
         }
         .into()
     }
+
+    fn source(&self) -> EffectSource {
+        EffectSource::Ability(AbilityDef::Halve)
+    }
 }
 
 impl Effect for Halve {
-    fn update(&mut self, delta: f32, myself: &Fighter, enemy: &Fighter) -> Vec<ModifierDesc> {
+    fn update(
+        &mut self,
+        delta: f32,
+        myself: &Fighter,
+        _enemy: &Fighter,
+        _rng: &mut StdRng,
+    ) -> Vec<ModifierDesc> {
         let mut modifiers = vec![];
 
         self.projectiles.retain_mut(|timer| {
@@ -31,12 +41,12 @@ impl Effect for Halve {
             if *timer >= 0.5 {
                 modifiers.extend(vec![
                     ModifierDesc {
-                        modifier: Modifier::AffectHP(-myself.hp * 0.5),
+                        modifier: Modifier::Damage(myself.hp * 0.5, DamageType::Pure),
                         target: Target::Myself,
                         value_kind: ValueKind::Units,
                     },
                     ModifierDesc {
-                        modifier: Modifier::AffectHP(-myself.hp * 0.5),
+                        modifier: Modifier::Damage(myself.hp * 0.5, DamageType::Pure),
                         target: Target::Enemy,
                         value_kind: ValueKind::Ulti,
                     },
@@ -47,7 +57,7 @@ impl Effect for Halve {
             }
         });
 
-        if myself.mana >= 100.0 {
+        if myself.ulti_ready() {
             self.projectiles.push(0.0);
             modifiers.extend(vec![
                 ModifierDesc {
@@ -0,0 +1,50 @@
+use rand::rngs::StdRng;
+
+use crate::battle::{
+    effect::Effect,
+    fight::Fighter,
+    modifier::{Modifier, ModifierDesc, Target, ValueKind},
+    status::StatusDesc,
+};
+
+/// Puts the status on the enemy every `period` seconds unless stunned
+#[derive(Debug)]
+pub struct Inflict {
+    status: StatusDesc,
+    period: f32,
+    timer: f32,
+}
+
+impl Inflict {
+    pub fn new(status: StatusDesc, period: f32) -> Self {
+        Self {
+            status,
+            period,
+            timer: 0.0,
+        }
+    }
+}
+
+impl Effect for Inflict {
+    fn update(
+        &mut self,
+        delta: f32,
+        myself: &Fighter,
+        _enemy: &Fighter,
+        _rng: &mut StdRng,
+    ) -> Vec<ModifierDesc> {
+        if myself.stunned() {
+            return vec![];
+        }
+        self.timer += delta;
+        if self.timer < self.period {
+            return vec![];
+        }
+        self.timer = 0.0;
+        vec![ModifierDesc {
+            modifier: Modifier::ApplyStatus(self.status),
+            target: Target::Enemy,
+            value_kind: ValueKind::Units,
+        }]
+    }
+}
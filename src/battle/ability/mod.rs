@@ -1,6 +1,11 @@
 use std::marker::PhantomData;
 
-use crate::battle::effect::Effect;
+use serde::{Deserialize, Serialize};
+
+use crate::battle::{
+    effect::{Effect, EffectSource, HasEffect},
+    status::StatusDesc,
+};
 
 #[derive(Debug)]
 pub struct Ability<T: Effect> {
@@ -15,12 +20,69 @@ impl<T: Effect> Clone for Ability<T> {
 
 impl<T: Effect> Ability<T> {
     pub fn new() -> Self {
-        Self {
-            _pd: PhantomData::default(),
+        Self { _pd: PhantomData }
+    }
+}
+
+fn reduce_mana() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum AbilityDef {
+    Attack,
+    RegenMana,
+    Beam {
+        heal: bool,
+        value: f32,
+        #[serde(default = "reduce_mana")]
+        reduce_mana: bool,
+    },
+    FireCube,
+    StarWars,
+    Halve,
+    Inflict {
+        status: StatusDesc,
+        period: f32,
+    },
+}
+
+impl AbilityDef {
+    pub fn id(&self) -> &'static str {
+        match self {
+            AbilityDef::Attack => "attack",
+            AbilityDef::RegenMana => "regen_mana",
+            AbilityDef::Beam { .. } => "beam",
+            AbilityDef::FireCube => "fire_cube",
+            AbilityDef::StarWars => "star_wars",
+            AbilityDef::Halve => "halve",
+            AbilityDef::Inflict { .. } => "inflict",
         }
     }
 }
 
+impl HasEffect for AbilityDef {
+    fn effect(&self) -> Box<dyn Effect> {
+        match *self {
+            AbilityDef::Attack => Ability::<Attack>::new().effect(),
+            AbilityDef::RegenMana => Ability::<RegenMana>::new().effect(),
+            AbilityDef::Beam {
+                heal,
+                value,
+                reduce_mana,
+            } => Beam::new(heal, value, reduce_mana).into(),
+            AbilityDef::FireCube => Ability::<FireCube>::new().effect(),
+            AbilityDef::StarWars => Ability::<StarWars>::new().effect(),
+            AbilityDef::Halve => Ability::<Halve>::new().effect(),
+            AbilityDef::Inflict { status, period } => Inflict::new(status, period).into(),
+        }
+    }
+
+    fn source(&self) -> EffectSource {
+        EffectSource::Ability(*self)
+    }
+}
+
 mod attack;
 pub use attack::Attack;
 
@@ -37,4 +99,7 @@ pub mod fire_cube;
 pub use fire_cube::FireCube;
 
 pub mod beam;
-pub use beam::Beam;
\ No newline at end of file
+pub use beam::Beam;
+
+mod inflict;
+pub use inflict::Inflict;
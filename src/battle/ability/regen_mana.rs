@@ -1,10 +1,12 @@
+use rand::rngs::StdRng;
+
 use crate::battle::{
-    effect::{Effect, HasEffect},
+    effect::{Effect, EffectSource, HasEffect},
     fight::Fighter,
     modifier::{Modifier, ModifierDesc, Target, ValueKind},
 };
 
-use super::Ability;
+use super::{Ability, AbilityDef};
 
 #[derive(Debug)]
 pub struct RegenMana {}
@@ -13,10 +15,20 @@ impl HasEffect for Ability<RegenMana> {
     fn effect(&self) -> Box<dyn Effect> {
         RegenMana {}.into()
     }
+
+    fn source(&self) -> EffectSource {
+        EffectSource::Ability(AbilityDef::RegenMana)
+    }
 }
 
 impl Effect for RegenMana {
-    fn update(&mut self, delta: f32, myself: &Fighter, enemy: &Fighter) -> Vec<ModifierDesc> {
+    fn update(
+        &mut self,
+        delta: f32,
+        myself: &Fighter,
+        _enemy: &Fighter,
+        _rng: &mut StdRng,
+    ) -> Vec<ModifierDesc> {
         vec![ModifierDesc {
             modifier: Modifier::AffectMana(myself.mana_regen * delta),
             target: Target::Myself,
@@ -1,10 +1,12 @@
+use rand::rngs::StdRng;
+
 use crate::battle::{
-    effect::{Effect, HasEffect},
+    effect::{Effect, EffectSource, HasEffect},
     fight::Fighter,
-    modifier::{Modifier, ModifierDesc, Target, ValueKind},
+    modifier::{DamageType, Modifier, ModifierDesc, Target, ValueKind},
 };
 
-use super::Ability;
+use super::{Ability, AbilityDef};
 
 #[derive(Debug)]
 pub struct StarWars {
@@ -24,17 +26,27 @@ impl HasEffect for Ability<StarWars> {
         }
         .into()
     }
+
+    fn source(&self) -> EffectSource {
+        EffectSource::Ability(AbilityDef::StarWars)
+    }
 }
 
 impl Effect for StarWars {
-    fn update(&mut self, delta: f32, myself: &Fighter, enemy: &Fighter) -> Vec<ModifierDesc> {
+    fn update(
+        &mut self,
+        delta: f32,
+        myself: &Fighter,
+        _enemy: &Fighter,
+        _rng: &mut StdRng,
+    ) -> Vec<ModifierDesc> {
         let mut modifiers = vec![];
 
         self.projectiles.retain_mut(|timer| {
             *timer += delta;
             if *timer >= 0.5 {
                 modifiers.push(ModifierDesc {
-                    modifier: Modifier::AffectHP(-60.0),
+                    modifier: Modifier::Damage(60.0, DamageType::Magical),
                     target: Target::Enemy,
                     value_kind: ValueKind::Ulti,
                 });
@@ -69,7 +81,7 @@ impl Effect for StarWars {
             self.current += 1;
         }
 
-        if myself.mana >= 100.0 {
+        if myself.ulti_ready() {
             self.swiborgs_ready.append(&mut self.swiborgs_charging);
             modifiers.extend(vec![
                 ModifierDesc {
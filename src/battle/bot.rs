@@ -0,0 +1,443 @@
+use std::{cmp::Reverse, error::Error, str::FromStr};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    card::{self, def::EffectKind, CardBranch, CardOps},
+    fight::{Fight, Owner},
+    modifier::Target,
+    player::Player,
+    Battle,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BotAction {
+    BuyCard(usize),
+    /// Index in the owned cards
+    SellCard(usize),
+    Reroll,
+    /// Keeps the bot's own shop for the next round
+    SetCardsLocked(bool),
+}
+
+/// Shop behaviour of a player driven by the computer.
+/// `act` is called until it returns `None`, the battle is only readable from here
+pub trait BotStrategy {
+    fn act(&self, battle: &Battle, me: &Player, rng: &mut StdRng) -> Option<BotAction>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Strategy {
+    Greedy,
+    #[default]
+    Economy,
+    Counter,
+    Random,
+    Lookahead,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 5] = [
+        Strategy::Greedy,
+        Strategy::Economy,
+        Strategy::Counter,
+        Strategy::Random,
+        Strategy::Lookahead,
+    ];
+
+    /// Handed out to bots in a game, lookahead is left for the simulation as too slow for a frame
+    pub const IN_GAME: [Strategy; 4] = [
+        Strategy::Greedy,
+        Strategy::Economy,
+        Strategy::Counter,
+        Strategy::Random,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Strategy::Greedy => "greedy",
+            Strategy::Economy => "economy",
+            Strategy::Counter => "counter",
+            Strategy::Random => "random",
+            Strategy::Lookahead => "lookahead",
+        }
+    }
+
+    pub fn bot(self, difficulty: Difficulty) -> Box<dyn BotStrategy> {
+        match self {
+            Strategy::Greedy => Box::new(Greedy),
+            Strategy::Economy => Box::new(Economy),
+            Strategy::Counter => Box::new(Counter),
+            Strategy::Random => Box::new(Random),
+            Strategy::Lookahead => Box::new(Lookahead {
+                samples: difficulty.lookahead_samples(),
+            }),
+        }
+    }
+}
+
+/// How well the bots play, chosen before the game
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Лёгкая",
+            Difficulty::Normal => "Средняя",
+            Difficulty::Hard => "Сложная",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|d| *d == self).unwrap();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Chance to stop shopping after every purchase
+    pub fn give_up_chance(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.4,
+            Difficulty::Normal | Difficulty::Hard => 0.0,
+        }
+    }
+
+    pub fn rerolls(self) -> usize {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 3,
+            Difficulty::Hard => usize::MAX,
+        }
+    }
+
+    pub fn locks_shop(self) -> bool {
+        self == Difficulty::Hard
+    }
+
+    /// Prefers branches that still have enough cards left in the pool
+    pub fn plans(self) -> bool {
+        self == Difficulty::Hard
+    }
+
+    /// Fights per opponent the lookahead bot runs for every option, a fixed count
+    /// stands in for a thinking time budget so the result depends on the seed only
+    pub fn lookahead_samples(self) -> usize {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 4,
+            Difficulty::Hard => 8,
+        }
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.id() == s)
+            .ok_or_else(|| format!("unknown difficulty {s}").into())
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|strategy| strategy.id() == s)
+            .ok_or_else(|| format!("unknown strategy {s}").into())
+    }
+}
+
+fn overlap(card: &dyn CardOps, branches: &[CardBranch]) -> u32 {
+    card.branches()
+        .iter()
+        .filter(|b| branches.contains(b))
+        .count() as u32
+}
+
+/// Cards still on sale that the player can pay for
+fn affordable(me: &Player) -> impl Iterator<Item = (usize, &dyn CardOps)> {
+    me.cards_reserved
+        .iter()
+        .enumerate()
+        .filter(|(_, (active, card))| *active && (card.cost() <= me.money || me.free_cards > 0))
+        .map(|(index, (_, card))| (index, card.as_ref()))
+}
+
+/// Buys the best scored card, rerolls while another card would still be affordable
+fn spend(battle: &Battle, me: &Player, score: impl Fn(&dyn CardOps) -> u32) -> Option<BotAction> {
+    let plan = |card: &dyn CardOps| {
+        card.branches()
+            .iter()
+            .filter(|b| me.branch_value(b) < battle.branch_max(b))
+            .count() as u32
+    };
+    let best = affordable(me)
+        .map(|(index, card)| match score(card) {
+            0 => (index, 0),
+            score if battle.difficulty.plans() => (index, score * 2 + plan(card)),
+            score => (index, score),
+        })
+        .filter(|(_, score)| *score > 0)
+        .max_by_key(|(index, score)| (*score, Reverse(*index)));
+    if let Some((index, _)) = best {
+        return Some(BotAction::BuyCard(index));
+    }
+    let cheapest = card::defs().iter().map(|def| def.cost).min().unwrap_or(0);
+    (me.money >= battle.ruleset.reroll_cost + cheapest).then_some(BotAction::Reroll)
+}
+
+/// Stacks the hero's branches and whatever it already owns
+pub struct Greedy;
+
+impl BotStrategy for Greedy {
+    fn act(&self, battle: &Battle, me: &Player, _: &mut StdRng) -> Option<BotAction> {
+        // an off-branch card goes when it pays for a card of the hero's branches
+        let wanted = me
+            .cards_reserved
+            .iter()
+            .filter(|(active, card)| *active && overlap(card.as_ref(), &me.hero.branches) > 0)
+            .map(|(_, card)| card.cost())
+            .min();
+        if let Some(cost) = wanted.filter(|cost| *cost > me.money) {
+            let sell = me.cards.iter().enumerate().position(|(index, card)| {
+                overlap(card.as_ref(), &me.hero.branches) == 0
+                    && me.money + me.refund(index, &battle.ruleset) >= cost
+            });
+            if let Some(index) = sell {
+                return Some(BotAction::SellCard(index));
+            }
+        }
+
+        spend(battle, me, |card| {
+            let owned = card
+                .branches()
+                .iter()
+                .map(|b| me.branch_value(b).min(1))
+                .sum::<u32>();
+            overlap(card, &me.hero.branches) * 2 + owned
+        })
+    }
+}
+
+/// Keeps enough money for the full interest and only spends the rest
+pub struct Economy;
+
+impl BotStrategy for Economy {
+    fn act(&self, battle: &Battle, me: &Player, rng: &mut StdRng) -> Option<BotAction> {
+        let spare = me.money.saturating_sub(battle.ruleset.interest_cap());
+        let wanted = me
+            .cards_reserved
+            .iter()
+            .enumerate()
+            .filter(|(_, (active, card))| *active && overlap(card.as_ref(), &me.hero.branches) > 0)
+            .map(|(index, (_, card))| (index, card.cost()))
+            .collect::<Vec<_>>();
+
+        if let Some((index, _)) = wanted.iter().find(|(_, cost)| *cost < spare) {
+            return Some(BotAction::BuyCard(*index));
+        }
+        // off-branch cards are bought only now and then
+        if let Some((index, _)) = affordable(me).find(|(_, card)| card.cost() < spare) {
+            if rng.gen::<f32>() < 0.1 {
+                return Some(BotAction::BuyCard(index));
+            }
+        }
+        // a reroll is only worth it if a card can still be bought afterwards
+        let cheapest = card::defs().iter().map(|def| def.cost).min().unwrap_or(0);
+        if spare >= battle.ruleset.reroll_cost + cheapest {
+            return Some(BotAction::Reroll);
+        }
+        // hold on to a wanted card until it can be afforded
+        let locked = !wanted.is_empty();
+        (me.cards_locked != locked).then_some(BotAction::SetCardsLocked(locked))
+    }
+}
+
+fn weakens_enemy(card: &dyn CardOps) -> bool {
+    card::defs()
+        .iter()
+        .find(|def| def.id == card.id())
+        .is_some_and(|def| match def.effect {
+            EffectKind::Aura { target, .. } | EffectKind::ApplyStatus { target, .. } => {
+                target == Target::Enemy
+            }
+            EffectKind::CancelRegen => true,
+            _ => false,
+        })
+}
+
+/// Looks at what the other living players stack and buys cards that weaken it
+pub struct Counter;
+
+impl BotStrategy for Counter {
+    fn act(&self, battle: &Battle, me: &Player, _: &mut StdRng) -> Option<BotAction> {
+        let opponents = battle
+            .players
+            .iter()
+            .filter(|p| p.hp > 0 && p.hero.id != me.hero.id)
+            .collect::<Vec<_>>();
+        let mut threats = [
+            CardBranch::Attack,
+            CardBranch::Regen,
+            CardBranch::Hp,
+            CardBranch::Mana,
+            CardBranch::Crit,
+            CardBranch::Evasion,
+        ]
+        .map(|branch| {
+            let value = opponents
+                .iter()
+                .map(|p| p.branch_value(&branch))
+                .sum::<u32>();
+            (branch, value)
+        });
+        threats.sort_by_key(|(_, value)| Reverse(*value));
+        let threats = threats
+            .iter()
+            .take(2)
+            .filter(|(_, value)| *value > 0)
+            .map(|(branch, _)| branch.clone())
+            .collect::<Vec<_>>();
+
+        spend(battle, me, |card| {
+            let counter = match weakens_enemy(card) {
+                true => overlap(card, &threats) * 2,
+                false => 0,
+            };
+            overlap(card, &me.hero.branches) + counter
+        })
+    }
+}
+
+/// Baseline for the others: buys or rerolls at random
+pub struct Random;
+
+impl BotStrategy for Random {
+    fn act(&self, battle: &Battle, me: &Player, rng: &mut StdRng) -> Option<BotAction> {
+        let mut actions = affordable(me)
+            .map(|(index, _)| BotAction::BuyCard(index))
+            .collect::<Vec<_>>();
+        if me.money >= battle.ruleset.reroll_cost {
+            actions.push(BotAction::Reroll);
+        }
+        if rng.gen::<f32>() < 0.2 {
+            return None;
+        }
+        actions.choose(rng).copied()
+    }
+}
+
+/// Simulates fights against the living opponents for every affordable card
+/// and buys the one that wins the most, if it beats keeping the money
+pub struct Lookahead {
+    /// fights against every opponent per option, every option is scored on the same fights
+    pub samples: usize,
+}
+
+impl Lookahead {
+    fn outcome(me: &Player, enemy: &Player, seed: u64) -> f32 {
+        let (winner, capture) = Fight::new(me, enemy, seed).run();
+        let state = capture.last();
+        let margin =
+            state.fighter1.hp / state.fighter1.max_hp - state.fighter2.hp / state.fighter2.max_hp;
+        (winner == Owner::Fighter1) as u32 as f32 + margin * 0.1
+    }
+}
+
+impl BotStrategy for Lookahead {
+    fn act(&self, battle: &Battle, me: &Player, rng: &mut StdRng) -> Option<BotAction> {
+        let options = affordable(me).map(|(index, _)| index).collect::<Vec<_>>();
+        if options.is_empty() {
+            return spend(battle, me, |_| 0);
+        }
+        let opponents = battle
+            .players
+            .iter()
+            .filter(|p| p.hp > 0 && p.hero.id != me.hero.id)
+            .collect::<Vec<_>>();
+        if opponents.is_empty() {
+            return None;
+        }
+
+        // keeping the money is the first option
+        let players = std::iter::once(None)
+            .chain(options.iter().map(Some))
+            .map(|index| {
+                let mut player = me.clone();
+                if let Some(index) = index {
+                    player.use_reserved_card(*index);
+                }
+                player
+            })
+            .collect::<Vec<_>>();
+        let mut scores = vec![0.0; players.len()];
+
+        for _ in 0..self.samples {
+            for enemy in &opponents {
+                let seed = rng.gen();
+                for (player, score) in players.iter().zip(&mut scores) {
+                    *score += Self::outcome(player, enemy, seed);
+                }
+            }
+        }
+
+        let (best, score) = scores[1..]
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap();
+        (*score > scores[0]).then_some(BotAction::BuyCard(options[best]))
+    }
+}
+
+#[test]
+fn strategies() {
+    use super::hero;
+
+    // two bots are enough, lookahead runs a lot of fights
+    let shop = |strategy| {
+        let players = hero::all().into_iter().take(2).map(Player::new).collect();
+        let mut battle = Battle::with_seed(players, 7);
+        for player in &mut battle.players {
+            player.strategy = strategy;
+            player.money = 2000;
+        }
+        for id in battle.players.iter().map(|p| p.hero.id).collect::<Vec<_>>() {
+            battle.ai(id);
+        }
+        battle.players
+    };
+
+    for strategy in Strategy::ALL {
+        assert_eq!(strategy.id().parse::<Strategy>().unwrap(), strategy);
+
+        let players = shop(strategy);
+        assert!(players.iter().any(|p| p.money < 2000), "{strategy:?}");
+        // same seed, same purchases
+        assert_eq!(format!("{players:?}"), format!("{:?}", shop(strategy)));
+    }
+    assert!("smart".parse::<Strategy>().is_err());
+    assert_eq!("hard".parse::<Difficulty>().unwrap(), Difficulty::Hard);
+    assert_eq!(Difficulty::Hard.next(), Difficulty::Easy);
+}
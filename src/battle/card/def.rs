@@ -0,0 +1,117 @@
+use std::error::Error;
+
+use serde::{Deserialize, Serialize};
+
+use crate::battle::{data, modifier::Target, status::StatusDesc};
+
+use super::CardBranch;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Stat {
+    Attack,
+    MaxHp,
+    /// Heal the enemy can cancel
+    Heal,
+    /// Heal the enemy can't cancel
+    Hp,
+    /// Nothing healed, the enemy's cancel effects see two heals
+    Regen,
+    Mana,
+    UltiAmp,
+    Crit,
+    Evasion,
+    Armor,
+    MagicResist,
+    DamageReduction,
+    HealReduction,
+    Shield,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Proc {
+    Attack,
+    Ulti,
+    Crit,
+    Evasion,
+}
+
+fn always() -> f32 {
+    1.0
+}
+
+/// How a card turns its per-level value into modifiers during a fight
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum EffectKind {
+    /// Constant `value` applied to the target's stat
+    Aura { stat: Stat, target: Target },
+    /// `value` percent of own attack added to the stat
+    FromAttack { stat: Stat },
+    /// `value` for every `step` of lost hp
+    PerLostHp { stat: Stat, step: f32 },
+    /// Heals `value` every `period` seconds
+    PeriodicHeal { period: f32 },
+    /// Heals `value` of lost hp every `period` seconds
+    PeriodicLostHpHeal { period: f32 },
+    /// `value` applied to the stat when the proc happens
+    OnProc {
+        proc: Proc,
+        stat: Stat,
+        #[serde(default = "always")]
+        chance: f32,
+    },
+    /// Every proc adds a `stack` lasting `duration` seconds, up to `value` in total, zero lasts one tick
+    Stacking {
+        proc: Proc,
+        stat: Stat,
+        stack: f32,
+        duration: f32,
+    },
+    /// Cancels each enemy heal with `value` chance
+    CancelRegen,
+    /// Applies the status to the target every `period` seconds from the start of the fight,
+    /// its amount is `value` or, `from_attack`, `value` percent of own attack
+    Status {
+        status: StatusDesc,
+        target: Target,
+        period: f32,
+        #[serde(default)]
+        from_attack: bool,
+    },
+    /// Applies the status to the target with `value` chance when the proc happens
+    ApplyStatus {
+        proc: Proc,
+        status: StatusDesc,
+        target: Target,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardDef {
+    pub id: String,
+    pub branches: Vec<CardBranch>,
+    pub max_level: u8,
+    pub cost: u32,
+    pub name: String,
+    pub desc: String,
+    pub values: Vec<f32>,
+    pub effect: EffectKind,
+}
+
+pub fn load() -> Result<Vec<CardDef>, Box<dyn Error>> {
+    let defs: Vec<CardDef> = data::load_dir("cards")?;
+    for (i, def) in defs.iter().enumerate() {
+        if def.values.len() != def.max_level as usize {
+            return Err(format!(
+                "card {} has {} values for {} levels",
+                def.id,
+                def.values.len(),
+                def.max_level
+            )
+            .into());
+        }
+        if defs[..i].iter().any(|d| d.id == def.id) {
+            return Err(format!("duplicate card {}", def.id).into());
+        }
+    }
+    Ok(defs)
+}
@@ -0,0 +1,177 @@
+use rand::{rngs::StdRng, Rng};
+
+use crate::battle::{
+    effect::Effect,
+    fight::{Fighter, Procs},
+    modifier::{DamageType, Modifier, ModifierDesc, Target, ValueKind},
+    status::StatusDesc,
+};
+
+use super::def::{EffectKind, Proc, Stat};
+
+#[derive(Debug)]
+pub struct CardEffect {
+    kind: EffectKind,
+    value: f32,
+    timer: f32,
+    instances: Vec<f32>,
+}
+
+impl CardEffect {
+    pub fn new(kind: EffectKind, value: f32) -> Self {
+        Self {
+            kind,
+            value,
+            timer: 0.0,
+            instances: vec![],
+        }
+    }
+
+    fn tick(&mut self, delta: f32, period: f32) -> bool {
+        self.timer += delta;
+        if self.timer >= period {
+            self.timer = 0.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn happened(procs: &Procs, proc: Proc) -> bool {
+    match proc {
+        Proc::Attack => procs.attack,
+        Proc::Ulti => procs.ulti,
+        Proc::Crit => procs.crit,
+        Proc::Evasion => procs.evasion,
+    }
+}
+
+fn modifiers(stat: Stat, value: f32, target: Target) -> Vec<ModifierDesc> {
+    let modifiers = match stat {
+        Stat::Attack => vec![Modifier::AffectAttack(value)],
+        Stat::MaxHp => vec![Modifier::AffectMaxHP(value)],
+        Stat::Heal => vec![Modifier::Heal(value), Modifier::Regen(value)],
+        Stat::Hp => vec![Modifier::Heal(value)],
+        Stat::Regen => vec![Modifier::Regen(value), Modifier::Regen(value)],
+        Stat::Mana => vec![Modifier::AffectMana(value)],
+        Stat::UltiAmp => vec![Modifier::AffectUltiAmp(value)],
+        Stat::Crit => vec![Modifier::AffectCrit(value)],
+        Stat::Evasion => vec![Modifier::AffectEvasion(value)],
+        Stat::Armor => vec![Modifier::AffectArmor(value)],
+        Stat::MagicResist => vec![Modifier::AffectMagicResist(value)],
+        Stat::DamageReduction => vec![Modifier::AffectDamageReduction(value)],
+        Stat::HealReduction => vec![Modifier::AffectHealReduction(value)],
+        Stat::Shield => vec![Modifier::AffectShield(value)],
+    };
+    modifiers
+        .into_iter()
+        .map(|modifier| ModifierDesc {
+            modifier,
+            target,
+            value_kind: ValueKind::Units,
+        })
+        .collect()
+}
+
+impl Effect for CardEffect {
+    fn update(
+        &mut self,
+        delta: f32,
+        myself: &Fighter,
+        enemy: &Fighter,
+        rng: &mut StdRng,
+    ) -> Vec<ModifierDesc> {
+        match self.kind {
+            EffectKind::Aura { stat, target } => modifiers(stat, self.value, target),
+            EffectKind::FromAttack { stat } => {
+                modifiers(stat, myself.attack * self.value / 100.0, Target::Myself)
+            }
+            EffectKind::PerLostHp { stat, step } => {
+                let count = (myself.hp_lost / step) as u32;
+                modifiers(stat, self.value * count as f32, Target::Myself)
+            }
+            EffectKind::PeriodicHeal { period } => match self.tick(delta, period) {
+                true => modifiers(Stat::Heal, self.value, Target::Myself),
+                false => vec![],
+            },
+            EffectKind::PeriodicLostHpHeal { period } => match self.tick(delta, period) {
+                true => modifiers(Stat::Heal, myself.hp_lost * self.value, Target::Myself),
+                false => vec![],
+            },
+            EffectKind::OnProc { proc, stat, chance } => {
+                if happened(&myself.procs, proc) && (chance >= 1.0 || rng.gen::<f32>() <= chance) {
+                    modifiers(stat, self.value, Target::Myself)
+                } else {
+                    vec![]
+                }
+            }
+            EffectKind::Stacking {
+                proc,
+                stat,
+                stack,
+                duration,
+            } => {
+                self.instances.retain_mut(|timer| {
+                    *timer += delta;
+                    *timer < duration
+                });
+                if happened(&myself.procs, proc) {
+                    self.instances.push(0.0);
+                }
+                let value = (self.instances.len() as f32 * stack).min(self.value);
+                modifiers(stat, value, Target::Myself)
+            }
+            EffectKind::Status {
+                status,
+                target,
+                period,
+                from_attack,
+            } => {
+                let due = self.timer == 0.0;
+                self.tick(delta, period);
+                if !due {
+                    return vec![];
+                }
+                let amount = match from_attack {
+                    true => myself.attack * self.value / 100.0,
+                    false => self.value,
+                };
+                vec![ModifierDesc {
+                    modifier: Modifier::ApplyStatus(StatusDesc {
+                        kind: status.kind.with_amount(amount),
+                        ..status
+                    }),
+                    target,
+                    value_kind: ValueKind::Units,
+                }]
+            }
+            EffectKind::ApplyStatus {
+                proc,
+                status,
+                target,
+            } => {
+                if happened(&myself.procs, proc) && rng.gen::<f32>() <= self.value {
+                    vec![ModifierDesc {
+                        modifier: Modifier::ApplyStatus(status),
+                        target,
+                        value_kind: ValueKind::Units,
+                    }]
+                } else {
+                    vec![]
+                }
+            }
+            EffectKind::CancelRegen => enemy
+                .procs
+                .regen
+                .iter()
+                .filter(|_| rng.gen::<f32>() <= self.value)
+                .map(|regen| ModifierDesc {
+                    modifier: Modifier::Damage(*regen, DamageType::Pure),
+                    target: Target::Enemy,
+                    value_kind: ValueKind::Units,
+                })
+                .collect(),
+        }
+    }
+}
@@ -1,12 +1,16 @@
-use std::marker::PhantomData;
+use std::sync::OnceLock;
 
 use dyn_clone::DynClone;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::battle::effect::Effect;
 
-use super::effect::HasEffect;
+use super::effect::{EffectSource, HasEffect};
 
-#[derive(Debug, Clone, PartialEq)]
+pub use def::CardDef;
+use effect::CardEffect;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CardBranch {
     Attack,
     Regen,
@@ -16,25 +20,10 @@ pub enum CardBranch {
     Evasion,
 }
 
-#[derive(Debug)]
-pub struct Card<T: Effect> {
-    pub id: &'static str,
-    pub branches: Vec<CardBranch>,
-    pub level: u8,
-    pub max_level: u8,
-    pub name: &'static str,
-    pub desc: &'static str,
-    pub cost: u32,
-    _pd: PhantomData<T>,
-}
-
-pub trait CardInfo {
-    fn id() -> &'static str;
-    fn branches() -> Vec<CardBranch>;
-    fn max_level() -> u8;
-    fn name() -> &'static str;
-    fn desc() -> &'static str;
-    fn cost() -> u32;
+#[derive(Debug, Clone)]
+pub struct Card {
+    def: &'static CardDef,
+    level: u8,
 }
 
 pub trait CardOps: HasEffect + DynClone {
@@ -50,16 +39,13 @@ pub trait CardOps: HasEffect + DynClone {
 
 dyn_clone::clone_trait_object!(CardOps);
 
-impl<T: Effect> CardOps for Card<T>
-where
-    Card<T>: HasEffect,
-{
+impl CardOps for Card {
     fn id(&self) -> &'static str {
-        self.id
+        &self.def.id
     }
 
     fn branches(&self) -> Vec<CardBranch> {
-        self.branches.clone()
+        self.def.branches.clone()
     }
 
     fn level(&self) -> u8 {
@@ -67,7 +53,7 @@ where
     }
 
     fn max_level(&self) -> u8 {
-        self.max_level
+        self.def.max_level
     }
 
     fn set_level(&mut self, level: u8) {
@@ -75,18 +61,81 @@ where
     }
 
     fn name(&self) -> &'static str {
-        self.name
+        &self.def.name
     }
 
     fn desc(&self) -> &'static str {
-        self.desc
+        &self.def.desc
     }
 
     fn cost(&self) -> u32 {
-        self.cost
+        self.def.cost
+    }
+}
+
+impl HasEffect for Card {
+    fn effect(&self) -> Box<dyn Effect> {
+        let value = self.def.values[self.level.saturating_sub(1) as usize];
+        CardEffect::new(self.def.effect, value).into()
+    }
+
+    fn source(&self) -> EffectSource {
+        EffectSource::Card(self.def.id.clone(), self.level)
+    }
+}
+
+// cards are stored as (id, level) and looked up in the loaded definitions
+impl Serialize for Box<dyn CardOps> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.id(), self.level()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<dyn CardOps> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_card(deserializer, 1)
+    }
+}
+
+fn deserialize_card<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    min_level: u8,
+) -> Result<Box<dyn CardOps>, D::Error> {
+    let (id, level) = <(String, u8)>::deserialize(deserializer)?;
+    let mut card = by_id(&id).ok_or_else(|| de::Error::custom(format!("unknown card {id}")))?;
+    if level < min_level || level > card.max_level() {
+        return Err(de::Error::custom(format!("card {id} has no level {level}")));
+    }
+    card.set_level(level);
+    Ok(card)
+}
+
+/// Card in the shop or the pool, level 0 until someone owns it
+struct ShopCard(Box<dyn CardOps>);
+
+impl<'de> Deserialize<'de> for ShopCard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_card(deserializer, 0).map(ShopCard)
     }
 }
 
+pub fn deserialize_pool<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Box<dyn CardOps>>, D::Error> {
+    let cards = Vec::<ShopCard>::deserialize(deserializer)?;
+    Ok(cards.into_iter().map(|card| card.0).collect())
+}
+
+pub fn deserialize_reserved<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<(bool, Box<dyn CardOps>)>, D::Error> {
+    let cards = Vec::<(bool, ShopCard)>::deserialize(deserializer)?;
+    Ok(cards
+        .into_iter()
+        .map(|(active, card)| (active, card.0))
+        .collect())
+}
+
 impl PartialEq for Box<dyn CardOps> {
     fn eq(&self, other: &Self) -> bool {
         self.id() == other.id()
@@ -95,67 +144,80 @@ impl PartialEq for Box<dyn CardOps> {
     }
 }
 
-impl<T: Effect> Clone for Card<T> {
-    fn clone(&self) -> Self {
-        Self {
-            id: self.id,
-            branches: self.branches.clone(),
-            level: self.level,
-            max_level: self.max_level,
-            name: self.name,
-            desc: self.desc,
-            cost: self.cost,
-            _pd: self._pd,
-        }
+impl Card {
+    pub fn new(def: &'static CardDef) -> Self {
+        Self { def, level: 1 }
     }
 }
 
-impl<T: Effect> Card<T> {
-    pub fn new(
-        id: &'static str,
-        branches: Vec<CardBranch>,
-        max_level: u8,
-        name: &'static str,
-        desc: &'static str,
-        cost: u32,
-    ) -> Self {
-        Self {
-            id,
-            branches,
-            level: 1,
-            max_level,
-            name,
-            desc,
-            cost,
-            _pd: PhantomData::default(),
+pub fn defs() -> &'static [CardDef] {
+    static DEFS: OnceLock<Vec<CardDef>> = OnceLock::new();
+    DEFS.get_or_init(|| def::load().unwrap_or_else(|err| panic!("failed to load cards: {err}")))
+}
+
+pub fn all() -> Vec<Box<dyn CardOps>> {
+    defs()
+        .iter()
+        .map(|def| Box::new(Card::new(def)) as Box<dyn CardOps>)
+        .collect()
+}
+
+pub fn by_id(id: &str) -> Option<Box<dyn CardOps>> {
+    all().into_iter().find(|card| card.id() == id)
+}
+
+pub fn effect_by_id(id: &str, level: u8) -> Option<Box<dyn HasEffect>> {
+    defs()
+        .iter()
+        .find(|def| def.id == id && (1..=def.max_level).contains(&level))
+        .map(|def| Box::new(Card { def, level }) as Box<dyn HasEffect>)
+}
+
+/// Description with only the values of the card's current level, e.g. `5/10/15` at level 2 gives `10`
+pub fn level_desc(card: &dyn CardOps) -> String {
+    let level = card.level().max(1) as usize;
+    card.desc()
+        .split(' ')
+        .map(|word| {
+            let values = word.split('/').collect::<Vec<_>>();
+            match values.len() == card.max_level() as usize && values.len() > 1 {
+                true => values[level - 1],
+                false => word,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn cards() {
+    let cards = all();
+    assert_eq!(cards.len(), 34);
+    for mut card in cards {
+        for level in 1..=card.max_level() {
+            card.set_level(level);
+            card.effect();
         }
     }
+
+    let mut card = by_id("exhaustion").unwrap();
+    card.set_level(2);
+    assert_eq!(
+        level_desc(card.as_ref()),
+        "Шанс 16% отменить восстановление противника"
+    );
+
+    for level in [0, card.max_level() + 1] {
+        assert!(ron::from_str::<Box<dyn CardOps>>(&format!("(\"exhaustion\", {level})")).is_err());
+        assert!(
+            ron::from_str::<Box<dyn HasEffect>>(&format!("Card(\"exhaustion\", {level})")).is_err()
+        );
+    }
+    let shop = |ron: &str| deserialize_reserved(&mut ron::Deserializer::from_str(ron).unwrap());
+    assert!(shop("[(true, (\"exhaustion\", 0))]").is_ok());
+    assert!(shop("[(true, (\"exhaustion\", 9))]").is_err());
 }
 
-pub mod agility_capsule;
-pub mod agility_web;
-pub mod capture_maneuver;
-pub mod combat_medkit;
-pub mod energy_drain;
-pub mod energy_source;
-pub mod exhaustion;
-pub mod healing_drone;
-pub mod healing_flow;
-pub mod hero_might;
-pub mod illness;
-pub mod life_essence;
-pub mod life_symbiosis;
-pub mod lucky_bullet;
-pub mod magic_generator;
-pub mod mana_crystal;
-pub mod plasma_charge;
-pub mod plasma_strike;
-pub mod power_drainer;
-pub mod precision_hit;
-pub mod shadow_bastion;
-pub mod shadow_caster;
-pub mod shadow_dance;
-pub mod shock_wave;
-pub mod shooter_luck;
-pub mod sign_of_misfortune;
-pub mod symbol_of_luck;
+pub mod def;
+pub mod effect;
+pub mod set;
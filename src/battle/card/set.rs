@@ -0,0 +1,99 @@
+use std::{error::Error, sync::OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::battle::{data, effect::Effect, player::Player};
+
+use super::{def::EffectKind, effect::CardEffect, CardBranch};
+
+/// Bonus unlocked by collecting points in one branch, or in every branch of a combo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetDef {
+    pub id: String,
+    pub name: String,
+    pub desc: String,
+    pub branches: Vec<CardBranch>,
+    /// points needed in each of the branches for every tier
+    pub tiers: Vec<u32>,
+    pub values: Vec<f32>,
+    pub effect: EffectKind,
+}
+
+impl SetDef {
+    /// Reached tier, 0 while the set is inactive
+    pub fn tier(&self, player: &Player) -> usize {
+        let points = self
+            .branches
+            .iter()
+            .map(|branch| player.branch_value(branch))
+            .min()
+            .unwrap_or(0);
+        self.tiers.iter().filter(|tier| points >= **tier).count()
+    }
+
+    pub fn effect(&self, tier: usize) -> Box<dyn Effect> {
+        CardEffect::new(self.effect, self.values[tier - 1]).into()
+    }
+}
+
+pub fn load() -> Result<Vec<SetDef>, Box<dyn Error>> {
+    let defs: Vec<SetDef> = data::load_dir("sets")?;
+    for (i, def) in defs.iter().enumerate() {
+        if def.branches.is_empty() || def.tiers.is_empty() {
+            return Err(format!("set {} has no branches or tiers", def.id).into());
+        }
+        if def.values.len() != def.tiers.len() {
+            return Err(format!(
+                "set {} has {} values for {} tiers",
+                def.id,
+                def.values.len(),
+                def.tiers.len()
+            )
+            .into());
+        }
+        if def.tiers.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(format!("set {} tiers must grow", def.id).into());
+        }
+        if defs[..i].iter().any(|d| d.id == def.id) {
+            return Err(format!("duplicate set {}", def.id).into());
+        }
+    }
+    Ok(defs)
+}
+
+pub fn defs() -> &'static [SetDef] {
+    static DEFS: OnceLock<Vec<SetDef>> = OnceLock::new();
+    DEFS.get_or_init(|| load().unwrap_or_else(|err| panic!("failed to load sets: {err}")))
+}
+
+/// Sets the player has unlocked with their reached tier
+pub fn active(player: &Player) -> impl Iterator<Item = (&'static SetDef, usize)> + '_ {
+    defs()
+        .iter()
+        .map(|def| (def, def.tier(player)))
+        .filter(|(_, tier)| *tier > 0)
+}
+
+#[test]
+fn sets() {
+    use super::by_id;
+    use crate::battle::hero;
+
+    assert!(!defs().is_empty());
+    assert!(defs().iter().all(|def| by_id(&def.id).is_none()));
+    let mut player = Player::new(hero::by_id("rasp").unwrap());
+    assert_eq!(active(&player).count(), 0);
+
+    let mut card = by_id("shooter_luck").unwrap();
+    card.set_level(4);
+    player.cards.push(card);
+    let ids = active(&player)
+        .map(|(def, tier)| (def.id.as_str(), tier))
+        .collect::<Vec<_>>();
+    assert!(ids.contains(&("precision", 1)));
+    assert!(ids.contains(&("elusiveness", 1)));
+    assert!(ids.contains(&("blade_dance", 2)));
+    for (def, tier) in active(&player) {
+        def.effect(tier);
+    }
+}
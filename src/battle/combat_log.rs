@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    fight::Owner,
+    modifier::DamageType,
+    status::{Dispel, StatusKind},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LogEvent {
+    /// `amount` before mitigation, `dealt` to hp after mitigation and shields
+    Damage {
+        damage_type: DamageType,
+        amount: f32,
+        dealt: f32,
+        absorbed: f32,
+        crit: bool,
+        evaded: bool,
+    },
+    Heal {
+        amount: f32,
+        healed: f32,
+    },
+    Shield(f32),
+    Status(StatusKind),
+    Dispel(Dispel),
+    Ulti,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub time: f32,
+    pub owner: Owner,
+    /// id of the ability or card that produced the event
+    pub source: Cow<'static, str>,
+    pub target: Owner,
+    pub event: LogEvent,
+}
+
+/// What a single ability or card did over the fight
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceStats {
+    pub owner: Owner,
+    pub source: Cow<'static, str>,
+    pub damage: f32,
+    pub absorbed: f32,
+    pub healed: f32,
+    pub shielded: f32,
+    pub hits: u32,
+    pub crits: u32,
+    pub evaded: u32,
+}
+
+impl SourceStats {
+    fn new(owner: Owner, source: Cow<'static, str>) -> Self {
+        Self {
+            owner,
+            source,
+            damage: 0.0,
+            absorbed: 0.0,
+            healed: 0.0,
+            shielded: 0.0,
+            hits: 0,
+            crits: 0,
+            evaded: 0,
+        }
+    }
+}
+
+/// Sums the log up per source, the most useful sources of each fighter go first
+pub fn breakdown(log: &[LogEntry]) -> Vec<SourceStats> {
+    let mut stats: Vec<SourceStats> = vec![];
+    for entry in log {
+        let index = match stats
+            .iter()
+            .position(|s| s.owner == entry.owner && s.source == entry.source)
+        {
+            Some(index) => index,
+            None => {
+                stats.push(SourceStats::new(entry.owner, entry.source.clone()));
+                stats.len() - 1
+            }
+        };
+        let s = &mut stats[index];
+        match entry.event {
+            LogEvent::Damage { evaded: true, .. } => s.evaded += 1,
+            LogEvent::Damage {
+                dealt,
+                absorbed,
+                crit,
+                ..
+            } => {
+                s.damage += dealt;
+                s.absorbed += absorbed;
+                s.hits += 1;
+                s.crits += crit as u32;
+            }
+            LogEvent::Heal { healed, .. } => s.healed += healed,
+            LogEvent::Shield(amount) => s.shielded += amount,
+            LogEvent::Status(_) | LogEvent::Dispel(_) | LogEvent::Ulti => {}
+        }
+    }
+    stats.sort_by(|a, b| {
+        let total = |s: &SourceStats| s.damage + s.absorbed + s.healed + s.shielded;
+        a.owner.cmp(&b.owner).then(total(b).total_cmp(&total(a)))
+    });
+    stats
+}
+
+#[test]
+fn breakdown_per_source() {
+    let damage = |dealt, crit, evaded| LogEvent::Damage {
+        damage_type: DamageType::Physical,
+        amount: 100.0,
+        dealt,
+        absorbed: 0.0,
+        crit,
+        evaded,
+    };
+    let entry = |owner, source: &'static str, event| LogEntry {
+        time: 0.0,
+        owner,
+        source: source.into(),
+        target: Owner::Fighter1,
+        event,
+    };
+    let log = vec![
+        entry(Owner::Fighter2, "attack", damage(50.0, false, false)),
+        entry(Owner::Fighter1, "attack", damage(80.0, true, false)),
+        entry(Owner::Fighter1, "attack", damage(0.0, false, true)),
+        entry(
+            Owner::Fighter1,
+            "healing_drone",
+            LogEvent::Heal {
+                amount: 200.0,
+                healed: 150.0,
+            },
+        ),
+    ];
+
+    let stats = breakdown(&log);
+    assert_eq!(stats.len(), 3);
+    assert_eq!(stats[0].source, "healing_drone");
+    assert_eq!(stats[0].healed, 150.0);
+    assert_eq!(
+        (stats[1].damage, stats[1].crits, stats[1].evaded),
+        (80.0, 1, 1)
+    );
+    assert_eq!(stats[2].owner, Owner::Fighter2);
+}
@@ -0,0 +1,119 @@
+use std::{error::Error, sync::OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use super::{ability::AbilityDef, card, data, hero, hero::Hero, player::Player, ruleset::Ruleset};
+
+/// What beating a creep gives the player
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Reward {
+    Money(u32),
+    /// next card from the shop is free
+    FreeCard,
+}
+
+/// Neutral monster every player fights on creep rounds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreepDef {
+    pub id: String,
+    pub name: String,
+    pub desc: String,
+    /// hero whose model and animations the creep borrows
+    pub model: String,
+    /// first round the creep shows up on, the latest one available is fought
+    pub round: u32,
+    pub hp: f32,
+    pub mana_regen: f32,
+    pub attack: f32,
+    pub attack_speed: f32,
+    pub crit: f32,
+    pub evasion: f32,
+    #[serde(default)]
+    pub armor: f32,
+    #[serde(default)]
+    pub magic_resist: f32,
+    pub abilities: Vec<AbilityDef>,
+    #[serde(default)]
+    pub cards: Vec<(String, u8)>,
+    /// hp the player loses to the creep
+    pub damage: u32,
+    pub reward: Reward,
+}
+
+impl CreepDef {
+    pub fn hero(&'static self) -> Hero {
+        Hero {
+            id: &self.id,
+            name: &self.name,
+            desc: &self.desc,
+            branches: vec![],
+            hp: self.hp,
+            mana_regen: self.mana_regen,
+            attack: self.attack,
+            attack_speed: self.attack_speed,
+            crit: self.crit,
+            evasion: self.evasion,
+            armor: self.armor,
+            magic_resist: self.magic_resist,
+            abils: self.abilities.clone(),
+        }
+    }
+
+    /// Synthetic player the fight is run against
+    pub fn player(&'static self, ruleset: &Ruleset) -> Player {
+        let mut player = Player::with_ruleset(self.hero(), ruleset);
+        for (id, level) in &self.cards {
+            let mut card = card::by_id(id).unwrap();
+            card.set_level(*level);
+            player.cards.push(card);
+        }
+        player
+    }
+}
+
+pub fn load() -> Result<Vec<CreepDef>, Box<dyn Error>> {
+    let defs: Vec<CreepDef> = data::load_dir("creeps")?;
+    for (i, def) in defs.iter().enumerate() {
+        if defs[..i].iter().any(|d| d.id == def.id) || hero::def(&def.id).is_some() {
+            return Err(format!("duplicate creep {}", def.id).into());
+        }
+        if hero::def(&def.model).is_none() {
+            return Err(format!("creep {} has unknown model {}", def.id, def.model).into());
+        }
+        for (id, level) in &def.cards {
+            match card::by_id(id) {
+                Some(card) if (1..=card.max_level()).contains(level) => {}
+                _ => return Err(format!("creep {} has bad card {id} {level}", def.id).into()),
+            }
+        }
+    }
+    Ok(defs)
+}
+
+pub fn defs() -> &'static [CreepDef] {
+    static DEFS: OnceLock<Vec<CreepDef>> = OnceLock::new();
+    DEFS.get_or_init(|| load().unwrap_or_else(|err| panic!("failed to load creeps: {err}")))
+}
+
+pub fn def(id: &str) -> Option<&'static CreepDef> {
+    defs().iter().find(|def| def.id == id)
+}
+
+pub fn for_round(round: u32) -> Option<&'static CreepDef> {
+    defs()
+        .iter()
+        .filter(|def| def.round <= round)
+        .max_by_key(|def| def.round)
+}
+
+#[test]
+fn creeps() {
+    assert!(for_round(0).is_none());
+    let creep = for_round(1).unwrap();
+    assert!(for_round(100).unwrap().round >= creep.round);
+
+    let player = creep.player(&Ruleset::default());
+    let json = serde_json::to_string(&player).unwrap();
+    let loaded: Player = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.hero.id, creep.id);
+}
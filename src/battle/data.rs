@@ -0,0 +1,108 @@
+use std::{env, error::Error, fs, path::Path, path::PathBuf, sync::OnceLock};
+
+use serde::de::DeserializeOwned;
+
+use super::{
+    card::{def, set},
+    creep, hero, item,
+    ruleset::Ruleset,
+};
+
+// path under assets and contents of every RON file, embedded by build.rs
+include!(concat!(env!("OUT_DIR"), "/data_files.rs"));
+
+/// Every file of the directory, sorted by name
+pub fn load_dir<T: DeserializeOwned>(dir: &str) -> Result<Vec<T>, Box<dyn Error>> {
+    files()?
+        .iter()
+        .filter(|(path, _)| Path::new(path).parent() == Some(Path::new(dir)))
+        .map(|(path, data)| parse(path, data))
+        .collect()
+}
+
+pub fn load_file<T: DeserializeOwned>(path: &str) -> Result<T, Box<dyn Error>> {
+    let (path, data) = files()?
+        .iter()
+        .find(|(p, _)| p == path)
+        .ok_or_else(|| format!("{path}: not found"))?;
+    parse(path, data)
+}
+
+// files in the assets folder next to the game replace and extend the embedded ones,
+// so data can be changed without a rebuild, the folder is read once
+fn files() -> Result<&'static [(String, String)], Box<dyn Error>> {
+    static FILES_CACHE: OnceLock<Result<Vec<(String, String)>, String>> = OnceLock::new();
+    let files = FILES_CACHE.get_or_init(|| {
+        let mut files = FILES
+            .iter()
+            .map(|(path, data)| (path.to_string(), data.to_string()))
+            .collect::<Vec<_>>();
+        let root = assets_root();
+        if root.is_dir() {
+            read_dir(&root, &root, &mut files).map_err(|err| err.to_string())?;
+        }
+        files.sort();
+        Ok(files)
+    });
+    Ok(files.as_ref().map_err(|err| err.as_str())?)
+}
+
+// `cargo run` points at the source tree, a shipped game keeps the folder next to the binary
+fn assets_root() -> PathBuf {
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .or_else(|| env::current_exe().ok()?.parent().map(Path::to_path_buf))
+        .unwrap_or_default()
+        .join("assets")
+}
+
+fn read_dir(
+    root: &Path,
+    dir: &Path,
+    files: &mut Vec<(String, String)>,
+) -> Result<(), Box<dyn Error>> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            read_dir(root, &path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "ron") {
+            let name = path
+                .strip_prefix(root)?
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let data =
+                fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+            files.retain(|(p, _)| *p != name);
+            files.push((name, data));
+        }
+    }
+    Ok(())
+}
+
+fn parse<T: DeserializeOwned>(path: &str, data: &str) -> Result<T, Box<dyn Error>> {
+    ron::from_str(data).map_err(|err| format!("{path}: {err}").into())
+}
+
+/// Loads all game data once, so a broken file is reported at startup instead of mid-game
+pub fn check() -> Result<(), Box<dyn Error>> {
+    // later kinds refer to the earlier ones
+    def::load()?;
+    set::load()?;
+    hero::load()?;
+    item::load()?;
+    creep::load()?;
+    Ruleset::shipped()?;
+    Ok(())
+}
+
+// per-user writable directory for saves
+pub fn user_dir() -> PathBuf {
+    env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+        .unwrap_or_default()
+        .join("twg-2024-2")
+}
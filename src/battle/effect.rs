@@ -1,11 +1,19 @@
 use std::fmt::Debug;
 
 use dyn_clone::DynClone;
+use rand::rngs::StdRng;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-use super::{fight::Fighter, modifier::ModifierDesc};
+use super::{ability::AbilityDef, card, fight::Fighter, modifier::ModifierDesc};
 
 pub trait Effect: Debug {
-    fn update(&mut self, delta: f32, myself: &Fighter, enemy: &Fighter) -> Vec<ModifierDesc>;
+    fn update(
+        &mut self,
+        delta: f32,
+        myself: &Fighter,
+        enemy: &Fighter,
+        rng: &mut StdRng,
+    ) -> Vec<ModifierDesc>;
 }
 
 impl<T: Effect + 'static> From<T> for Box<dyn Effect> {
@@ -16,6 +24,7 @@ impl<T: Effect + 'static> From<T> for Box<dyn Effect> {
 
 pub trait HasEffect: Debug + Send + Sync + DynClone {
     fn effect(&self) -> Box<dyn Effect>;
+    fn source(&self) -> EffectSource;
 }
 
 dyn_clone::clone_trait_object!(HasEffect);
@@ -25,3 +34,26 @@ impl<T: HasEffect + 'static> From<T> for Box<dyn HasEffect> {
         Box::new(value)
     }
 }
+
+/// Registry key an effect holder is serialized as
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EffectSource {
+    Ability(AbilityDef),
+    Card(String, u8),
+}
+
+impl Serialize for Box<dyn HasEffect> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.source().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<dyn HasEffect> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match EffectSource::deserialize(deserializer)? {
+            EffectSource::Ability(ability) => Ok(ability.into()),
+            EffectSource::Card(id, level) => card::effect_by_id(&id, level)
+                .ok_or_else(|| de::Error::custom(format!("unknown card {id} {level}"))),
+        }
+    }
+}
@@ -1,14 +1,25 @@
-use std::cmp::Ordering;
+use std::{borrow::Cow, cmp::Ordering};
 
-use rand::random;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 
-use crate::battle::modifier::{Modifier, ModifierDesc, Target};
+use crate::battle::modifier::{DamageType, Modifier, ModifierDesc, Target};
 
-use super::{card::CardBranch, effect::Effect, hero::Hero, modifier::ValueKind, player::Player};
+use super::{
+    card::{set, CardBranch},
+    combat_log::{LogEntry, LogEvent},
+    effect::{Effect, HasEffect},
+    hero::Hero,
+    modifier::ValueKind,
+    player::Player,
+    status::{StatusKind, Statuses},
+};
 
 pub const DURATION: f32 = 60.0;
+// share of the shield pool lost every second
+pub const SHIELD_DECAY: f32 = 0.1;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Procs {
     pub attack: bool,
     pub ulti: bool,
@@ -17,7 +28,7 @@ pub struct Procs {
     pub evasion: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Branches {
     attack: f32,
     regen: f32,
@@ -40,7 +51,7 @@ impl Branches {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Fighter {
     pub hero: Hero,
     pub procs: Procs,
@@ -49,13 +60,19 @@ pub struct Fighter {
     pub hp: f32,
     pub max_hp: f32,
     pub hp_lost: f32,
+    pub shield: f32,
     pub mana: f32,
     pub mana_regen: f32,
     pub attack: f32,
     pub attack_speed: f32,
     pub crit: f32,
     pub evasion: f32,
+    pub armor: f32,
+    pub magic_resist: f32,
+    pub damage_reduction: f32,
+    pub heal_reduction: f32,
     pub ulti_amp: f32,
+    pub statuses: Statuses,
 }
 
 impl Fighter {
@@ -68,13 +85,19 @@ impl Fighter {
             hp: player.hero.hp,
             max_hp: player.hero.hp,
             hp_lost: 0.0,
+            shield: 0.0,
             mana: 0.0,
             mana_regen: player.hero.mana_regen,
             attack: player.hero.attack,
             attack_speed: player.hero.attack_speed,
             crit: player.hero.crit,
             evasion: player.hero.evasion,
+            armor: player.hero.armor,
+            magic_resist: player.hero.magic_resist,
+            damage_reduction: 0.0,
+            heal_reduction: 0.0,
             ulti_amp: 1.0,
+            statuses: Statuses::default(),
         }
     }
 
@@ -89,23 +112,90 @@ impl Fighter {
         self.attack_speed = self.hero.attack_speed + self.branches.attack * 0.1;
         self.crit = self.hero.crit + self.branches.crit;
         self.evasion = self.hero.evasion + self.branches.evasion;
+        self.armor = self.hero.armor;
+        self.magic_resist = self.hero.magic_resist;
+        self.damage_reduction = 0.0;
+        self.heal_reduction = 0.0;
         self.ulti_amp = 1.0 + self.branches.mana * 0.2;
+        self.attack_speed *= 1.0 - self.statuses.slow();
+        self.evasion += self.statuses.evasion();
+    }
+
+    pub fn mitigate(&self, damage: f32, damage_type: DamageType) -> f32 {
+        let damage = match damage_type {
+            DamageType::Physical if self.armor >= 0.0 => damage * 100.0 / (100.0 + self.armor),
+            DamageType::Physical => damage * (2.0 - 100.0 / (100.0 - self.armor)),
+            DamageType::Magical => damage * (1.0 - self.magic_resist.clamp(-1.0, 0.9)),
+            DamageType::Pure => return damage,
+        };
+        damage * (1.0 - self.damage_reduction.clamp(0.0, 0.9))
+    }
+
+    pub fn shield_total(&self) -> f32 {
+        self.shield
+            + self
+                .statuses
+                .iter()
+                .map(|s| match s.kind {
+                    StatusKind::Shield(left) => left,
+                    _ => 0.0,
+                })
+                .sum::<f32>()
+    }
+
+    /// Timed shields go first, then the pool, returns the damage left for hp
+    fn absorb(&mut self, damage: f32) -> f32 {
+        let damage = self.statuses.absorb(damage);
+        let absorbed = self.shield.min(damage);
+        self.shield -= absorbed;
+        damage - absorbed
+    }
+
+    pub fn heal_factor(&self) -> f32 {
+        let poison = match self.statuses.has(|k| matches!(k, StatusKind::Poison(_))) {
+            true => 0.5,
+            false => 1.0,
+        };
+        poison * (1.0 - self.heal_reduction.clamp(0.0, 1.0))
+    }
+
+    pub fn stunned(&self) -> bool {
+        self.statuses.has(|k| *k == StatusKind::Stun)
+    }
+
+    /// Full mana and neither stunned nor silenced
+    pub fn ulti_ready(&self) -> bool {
+        self.mana >= 100.0 && !self.stunned() && !self.silenced()
+    }
+
+    pub fn silenced(&self) -> bool {
+        self.statuses.has(|k| *k == StatusKind::Silence)
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Owner {
     Fighter1,
     Fighter2,
 }
 
+impl Owner {
+    pub fn other(&self) -> Owner {
+        match self {
+            Owner::Fighter1 => Owner::Fighter2,
+            Owner::Fighter2 => Owner::Fighter1,
+        }
+    }
+}
+
 pub struct Fight<'a> {
-    effects: Vec<(Box<dyn Effect>, Owner)>,
-    player1: &'a mut Player,
-    player2: &'a mut Player,
+    effects: Vec<(Box<dyn Effect>, Owner, &'static str)>,
+    player1: &'a Player,
+    player2: &'a Player,
+    rng: StdRng,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct State {
     pub fighter1: Fighter,
     pub fighter2: Fighter,
@@ -113,9 +203,10 @@ pub struct State {
     pub modifiers: Vec<(Owner, ModifierDesc)>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FightCapture {
     states: Vec<(f32, State)>,
+    pub log: Vec<LogEntry>,
 }
 
 impl FightCapture {
@@ -159,23 +250,60 @@ impl FightCapture {
 }
 
 impl<'a> Fight<'a> {
-    pub fn new(p1: &'a mut Player, p2: &'a mut Player) -> Self {
+    pub fn new(p1: &'a Player, p2: &'a Player, seed: u64) -> Self {
         Self {
             effects: vec![]
                 .into_iter()
-                .chain(p1.hero.abils.iter().map(|a| (a.effect(), Owner::Fighter1)))
-                .chain(p2.hero.abils.iter().map(|a| (a.effect(), Owner::Fighter2)))
-                .chain(p1.cards.iter().map(|c| (c.effect(), Owner::Fighter1)))
-                .chain(p2.cards.iter().map(|c| (c.effect(), Owner::Fighter2)))
+                .chain(
+                    p1.hero
+                        .abils
+                        .iter()
+                        .map(|a| (a.effect(), Owner::Fighter1, a.id())),
+                )
+                .chain(
+                    p2.hero
+                        .abils
+                        .iter()
+                        .map(|a| (a.effect(), Owner::Fighter2, a.id())),
+                )
+                .chain(
+                    p1.cards
+                        .iter()
+                        .map(|c| (c.effect(), Owner::Fighter1, c.id())),
+                )
+                .chain(
+                    p2.cards
+                        .iter()
+                        .map(|c| (c.effect(), Owner::Fighter2, c.id())),
+                )
+                .chain(
+                    p1.items
+                        .iter()
+                        .map(|i| (i.effect(), Owner::Fighter1, i.id())),
+                )
+                .chain(
+                    p2.items
+                        .iter()
+                        .map(|i| (i.effect(), Owner::Fighter2, i.id())),
+                )
+                .chain(
+                    set::active(p1)
+                        .map(|(def, tier)| (def.effect(tier), Owner::Fighter1, def.id.as_str())),
+                )
+                .chain(
+                    set::active(p2)
+                        .map(|(def, tier)| (def.effect(tier), Owner::Fighter2, def.id.as_str())),
+                )
                 .collect(),
             player1: p1,
             player2: p2,
+            rng: StdRng::seed_from_u64(seed),
         }
     }
 
     pub fn run(&mut self) -> (Owner, FightCapture) {
         let fps = 100.0;
-        let delta = 1.0 / fps as f32;
+        let delta = 1.0 / fps;
 
         let mut fighter1 = Fighter::new(self.player1);
         let mut fighter2 = Fighter::new(self.player2);
@@ -190,37 +318,74 @@ impl<'a> Fight<'a> {
                     modifiers: vec![],
                 },
             )],
+            log: vec![],
         };
 
         let mut winner = None;
 
         for time in 0..(DURATION * fps) as u32 {
-            let time = time as f32 / fps as f32;
+            let time = time as f32 / fps;
 
+            let rng = &mut self.rng;
             let mut modifiers = self
                 .effects
                 .iter_mut()
-                .flat_map(|(effect, owner)| {
+                .flat_map(|(effect, owner, source)| {
                     let (myself, enemy) = match owner {
                         Owner::Fighter1 => (&mut fighter1, &mut fighter2),
                         Owner::Fighter2 => (&mut fighter2, &mut fighter1),
                     };
                     effect
-                        .update(delta, myself, enemy)
+                        .update(delta, myself, enemy, rng)
                         .into_iter()
-                        .map(|m| (*owner, m))
+                        .map(|m| (*owner, Cow::Borrowed(*source), m))
                 })
                 .collect::<Vec<_>>();
-            modifiers.sort_by_key(|(o, m)| (*o, m.key()));
+            for (holder, fighter) in [
+                (Owner::Fighter1, &mut fighter1),
+                (Owner::Fighter2, &mut fighter2),
+            ] {
+                fighter.shield *= 1.0 - SHIELD_DECAY * delta;
+                for (source, source_id, damage) in fighter.statuses.update(delta) {
+                    modifiers.push((
+                        source,
+                        source_id,
+                        ModifierDesc {
+                            modifier: Modifier::StatusDamage(damage),
+                            target: if source == holder {
+                                Target::Myself
+                            } else {
+                                Target::Enemy
+                            },
+                            value_kind: ValueKind::Units,
+                        },
+                    ));
+                }
+            }
+            modifiers.sort_by_key(|(o, _, m)| (*o, m.key()));
 
             fighter1.prepare();
             fighter2.prepare();
 
-            for (owner, m) in &modifiers {
+            // the log of this tick starts here, crits are attached to its damage entries
+            let tick_log = capture.log.len();
+            for (owner, source, m) in &modifiers {
                 let (mut myself, mut enemy) = match owner {
                     Owner::Fighter1 => (&mut fighter1, &mut fighter2),
                     Owner::Fighter2 => (&mut fighter2, &mut fighter1),
                 };
+                let mut log = |target: Target, event| {
+                    capture.log.push(LogEntry {
+                        time,
+                        owner: *owner,
+                        source: source.clone(),
+                        target: match target {
+                            Target::Myself => *owner,
+                            Target::Enemy => owner.other(),
+                        },
+                        event,
+                    })
+                };
                 let ulti_amp = myself.ulti_amp;
                 let target = match m.target {
                     Target::Myself => &mut myself,
@@ -233,22 +398,61 @@ impl<'a> Fight<'a> {
                     Modifier::AffectAttackSpeed(val) => {
                         target.attack_speed = (target.attack_speed + val).max(0.0);
                     }
-                    Modifier::AffectHP(val) => {
+                    Modifier::Damage(val, damage_type) => {
                         let val = match m.value_kind {
                             ValueKind::Ulti => ulti_amp * val,
                             _ => val,
                         };
-                        let new_hp = (target.hp + val).max(0.0).min(target.max_hp);
-                        if val < 0.0 {
-                            if random::<f32>() <= target.evasion {
-                                target.next_procs.evasion = true;
-                            } else {
-                                target.hp = new_hp;
-                                target.hp_lost -= val;
-                            }
+                        let evaded = self.rng.gen::<f32>() <= target.evasion;
+                        let (mut dealt, mut absorbed) = (0.0, 0.0);
+                        if evaded {
+                            target.next_procs.evasion = true;
                         } else {
-                            target.hp = new_hp;
+                            let mitigated = target.mitigate(val, damage_type);
+                            dealt = target.absorb(mitigated);
+                            absorbed = mitigated - dealt;
+                            target.hp = (target.hp - dealt).max(0.0);
+                            target.hp_lost += dealt;
                         }
+                        log(
+                            m.target,
+                            LogEvent::Damage {
+                                damage_type,
+                                amount: val,
+                                dealt,
+                                absorbed,
+                                crit: false,
+                                evaded,
+                            },
+                        );
+                    }
+                    Modifier::Heal(val) => {
+                        let val = match m.value_kind {
+                            ValueKind::Ulti => ulti_amp * val,
+                            _ => val,
+                        };
+                        let hp = target.hp;
+                        target.hp = (target.hp + val * target.heal_factor()).min(target.max_hp);
+                        let healed = target.hp - hp;
+                        log(
+                            m.target,
+                            LogEvent::Heal {
+                                amount: val,
+                                healed,
+                            },
+                        );
+                    }
+                    Modifier::TrueHeal(val) => {
+                        let hp = target.hp;
+                        target.hp = (target.hp + val).min(target.max_hp);
+                        let healed = target.hp - hp;
+                        log(
+                            m.target,
+                            LogEvent::Heal {
+                                amount: val,
+                                healed,
+                            },
+                        );
                     }
                     Modifier::AffectMaxHP(val) => {
                         let ratio = target.hp / target.max_hp;
@@ -256,7 +460,10 @@ impl<'a> Fight<'a> {
                         target.hp = target.max_hp * ratio;
                     }
                     Modifier::AffectMana(val) => {
-                        target.mana = (target.mana + val).max(0.0).min(100.0);
+                        if val > 0.0 && (target.stunned() || target.silenced()) {
+                            continue;
+                        }
+                        target.mana = (target.mana + val).clamp(0.0, 100.0);
                     }
                     Modifier::AffectUltiAmp(val) => {
                         target.ulti_amp += val;
@@ -267,18 +474,69 @@ impl<'a> Fight<'a> {
                     Modifier::AffectEvasion(val) => {
                         target.evasion += val;
                     }
+                    Modifier::AffectArmor(val) => {
+                        target.armor += val;
+                    }
+                    Modifier::AffectMagicResist(val) => {
+                        target.magic_resist += val;
+                    }
+                    Modifier::AffectDamageReduction(val) => {
+                        target.damage_reduction += val;
+                    }
+                    Modifier::AffectHealReduction(val) => {
+                        target.heal_reduction += val;
+                    }
+                    Modifier::AffectShield(val) => {
+                        target.shield = (target.shield + val).clamp(0.0, target.max_hp);
+                        if val > 0.0 {
+                            log(m.target, LogEvent::Shield(val));
+                        }
+                    }
+                    Modifier::ApplyStatus(desc) => {
+                        target.statuses.apply(desc, *owner, source.clone());
+                        log(m.target, LogEvent::Status(desc.kind));
+                    }
+                    Modifier::Dispel(dispel) => {
+                        target.statuses.dispel(dispel);
+                        log(m.target, LogEvent::Dispel(dispel));
+                    }
+                    Modifier::StatusDamage(val) => {
+                        let dealt = target.absorb(val);
+                        target.hp = (target.hp - dealt).max(0.0);
+                        target.hp_lost += dealt;
+                        log(
+                            m.target,
+                            LogEvent::Damage {
+                                damage_type: DamageType::Pure,
+                                amount: val,
+                                dealt,
+                                absorbed: val - dealt,
+                                crit: false,
+                                evaded: false,
+                            },
+                        );
+                    }
                     // markers
                     Modifier::NormalAttack => {
                         myself.next_procs.attack = true;
                     }
                     Modifier::Ulti => {
                         myself.next_procs.ulti = true;
+                        log(Target::Myself, LogEvent::Ulti);
                     }
                     Modifier::Regen(val) => {
                         myself.next_procs.regen.push(val);
                     }
                     Modifier::Crit => {
                         myself.next_procs.crit = true;
+                        if let Some(LogEvent::Damage { crit, .. }) = capture.log[tick_log..]
+                            .iter_mut()
+                            .rev()
+                            .find(|e| e.owner == *owner && e.source == *source)
+                            .map(|e| &mut e.event)
+                        {
+                            *crit = true;
+                        }
                     }
                     Modifier::Evasion => {
                         myself.next_procs.evasion = true;
@@ -307,8 +565,8 @@ impl<'a> Fight<'a> {
                     State {
                         fighter1: fighter1.clone(),
                         fighter2: fighter2.clone(),
-                        winner: winner.clone(),
-                        modifiers,
+                        winner,
+                        modifiers: modifiers.into_iter().map(|(o, _, m)| (o, m)).collect(),
                     },
                 ));
 
@@ -339,24 +597,87 @@ impl<'a> Fight<'a> {
             }
         };
 
-        let (w, l) = match winner {
-            Owner::Fighter1 => (&mut self.player1, &mut self.player2),
-            Owner::Fighter2 => (&mut self.player2, &mut self.player1),
-        };
-
-        w.money += (w.money / 100).min(10) * 10;
-        l.money += (l.money / 100).min(10) * 10;
-        w.money += 250;
-        l.money += 250;
-
-        w.money += 50;
-        l.money += w.attack * 15;
-
-        l.hp = (l.hp - w.attack as i32).max(0);
+        (winner, capture)
+    }
+}
 
-        l.attack = 3;
-        w.attack = (w.attack + 1).min(10);
+#[test]
+fn statuses_in_fight() {
+    use super::{ability::AbilityDef, card, hero, status::StatusDesc};
+
+    let stun = StatusDesc {
+        kind: StatusKind::Stun,
+        duration: 1.0,
+        max_stacks: 1,
+        dispellable: true,
+    };
+    let mut stunner = Player::new(hero::by_id("nulch").unwrap());
+    stunner.hero.abils.push(AbilityDef::Inflict {
+        status: stun,
+        period: 0.01,
+    });
+    let mut dancer = Player::new(hero::by_id("rasp").unwrap());
+    let mut dance = card::by_id("shadow_dance").unwrap();
+    dance.set_level(5);
+    dancer.cards.push(dance);
+
+    let (winner, capture) = Fight::new(&stunner, &dancer, 1).run();
+    assert_eq!(winner, Owner::Fighter1);
+
+    // stunned from the first tick, the dancer never attacks, gains mana or ults
+    for (_, state) in &capture.states[1..] {
+        assert!(state.fighter2.stunned());
+        assert_eq!(state.fighter2.mana, 0.0);
+        assert!(!state
+            .modifiers
+            .iter()
+            .any(|(owner, m)| *owner == Owner::Fighter2
+                && matches!(m.modifier, Modifier::NormalAttack | Modifier::Ulti)));
+        assert_eq!(state.fighter2.statuses.evasion(), 0.12);
+    }
+    // the dance is put on once for the whole fight instead of every tick
+    let dances = capture
+        .log
+        .iter()
+        .filter(|e| matches!(e.event, LogEvent::Status(StatusKind::Evasion(_))))
+        .count();
+    assert_eq!(dances, 1);
+
+    let mut fighter = Fighter::new(&dancer);
+    fighter.mana = 100.0;
+    assert!(fighter.ulti_ready());
+    fighter
+        .statuses
+        .apply(stun, Owner::Fighter1, "inflict".into());
+    assert!(!fighter.ulti_ready());
+}
 
-        (winner, capture)
+#[test]
+fn silence_blocks_ulti() {
+    use super::{ability::AbilityDef, hero, status::StatusDesc};
+
+    let silence = StatusDesc {
+        kind: StatusKind::Silence,
+        duration: 1.0,
+        max_stacks: 1,
+        dispellable: true,
+    };
+    // fills its mana on the first tick, before the silence lands
+    let mut caster = Player::new(hero::by_id("rasp").unwrap());
+    caster.hero.mana_regen = 10000.0;
+    let mut silencer = Player::new(hero::by_id("nulch").unwrap());
+    silencer.hero.abils.push(AbilityDef::Inflict {
+        status: silence,
+        period: 0.01,
+    });
+
+    let (_, capture) = Fight::new(&caster, &silencer, 1).run();
+    for (_, state) in &capture.states[1..] {
+        assert!(state.fighter1.silenced());
+        assert_eq!(state.fighter1.mana, 100.0);
+        assert!(!state
+            .modifiers
+            .iter()
+            .any(|(owner, m)| *owner == Owner::Fighter1 && matches!(m.modifier, Modifier::Ulti)));
     }
 }
@@ -0,0 +1,57 @@
+use std::sync::OnceLock;
+
+use super::{
+    hero::{self, Hero, HeroDef},
+    player::Player,
+};
+
+/// Copy of a hero fought by the odd player out, a fight against it doesn't touch the original
+#[derive(Debug)]
+pub struct GhostDef {
+    pub id: String,
+    pub name: String,
+    pub hero: &'static HeroDef,
+}
+
+impl GhostDef {
+    pub fn hero(&'static self) -> Hero {
+        Hero {
+            id: &self.id,
+            name: &self.name,
+            ..Hero::new(self.hero)
+        }
+    }
+}
+
+pub fn defs() -> &'static [GhostDef] {
+    static DEFS: OnceLock<Vec<GhostDef>> = OnceLock::new();
+    DEFS.get_or_init(|| {
+        hero::defs()
+            .iter()
+            .map(|hero| GhostDef {
+                id: format!("ghost_{}", hero.id),
+                name: format!("{} (призрак)", hero.name),
+                hero,
+            })
+            .collect()
+    })
+}
+
+pub fn def(id: &str) -> Option<&'static GhostDef> {
+    defs().iter().find(|def| def.id == id)
+}
+
+pub fn of_hero(id: &str) -> &'static GhostDef {
+    defs()
+        .iter()
+        .find(|def| def.hero.id == id)
+        .unwrap_or_else(|| panic!("unknown hero {id}"))
+}
+
+/// Same loadout under the ghost's id
+pub fn of(player: &Player) -> Player {
+    Player {
+        hero: of_hero(player.hero.id).hero(),
+        ..player.clone()
+    }
+}
@@ -1,8 +1,8 @@
-use super::{
-    ability::{Ability, Attack, Beam, FireCube, Halve, RegenMana, StarWars},
-    card::CardBranch,
-    effect::HasEffect,
-};
+use std::{error::Error, sync::OnceLock};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{ability::AbilityDef, card::CardBranch, creep, data, ghost};
 
 #[derive(Debug, Clone)]
 pub struct Hero {
@@ -16,126 +16,159 @@ pub struct Hero {
     pub attack_speed: f32,
     pub crit: f32,
     pub evasion: f32,
-    pub abils: Vec<Box<dyn HasEffect>>,
-}
-
-pub fn nulch() -> Hero {
-    Hero {
-        id: "nulch",
-        name: "Нульч",
-        desc: "Организатор конкурса.\nУникальная способность - лазерные удары с двух далёких космических спутников.",
-        branches: vec![CardBranch::Regen, CardBranch::Mana],
-        hp: 1100.0,
-        mana_regen: 10.0,
-        attack: 25.0,
-        attack_speed: 0.95,
-        crit: 0.16,
-        evasion: 0.15,
-        abils: vec![
-            Ability::<Attack>::new().into(),
-            Ability::<RegenMana>::new().into(),
-            Ability::<Beam<true, 150, true>>::new().into(),
-            Ability::<Beam<false, 150, false>>::new().into(),
-        ],
-    }
+    pub armor: f32,
+    pub magic_resist: f32,
+    pub abils: Vec<AbilityDef>,
 }
 
-pub fn rasp() -> Hero {
-    Hero {
-        id: "rasp",
-        name: "Расп",
-        desc: "Это твоя мамка, анон.\nУникальная способность - огненный куб.",
-        branches: vec![CardBranch::Attack, CardBranch::Mana],
-        hp: 1200.0,
-        mana_regen: 10.0,
-        attack: 22.0,
-        attack_speed: 1.04,
-        crit: 0.11,
-        evasion: 0.18,
-        abils: vec![
-            Ability::<Attack>::new().into(),
-            Ability::<RegenMana>::new().into(),
-            Ability::<FireCube>::new().into(),
-        ],
+impl Serialize for Hero {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id)
     }
 }
 
-pub fn dtyan() -> Hero {
-    Hero {
-        id: "dtyan",
-        name: "Деревотян",
-        desc: "Вырасти дерево, если сможешь!\nУникальная способность - лечащий луч.",
-        branches: vec![CardBranch::Regen, CardBranch::Evasion],
-        hp: 1400.0,
-        mana_regen: 10.0,
-        attack: 34.0,
-        attack_speed: 0.75,
-        crit: 0.20,
-        evasion: 0.08,
-        abils: vec![
-            Ability::<Attack>::new().into(),
-            Ability::<RegenMana>::new().into(),
-            Ability::<Beam<true, 300>>::new().into(),
-        ],
+impl<'de> Deserialize<'de> for Hero {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        by_id(&id)
+            .or_else(|| creep::def(&id).map(creep::CreepDef::hero))
+            .or_else(|| ghost::def(&id).map(ghost::GhostDef::hero))
+            .ok_or_else(|| de::Error::custom(format!("unknown hero {id}")))
     }
 }
 
-pub fn dimas() -> Hero {
-    Hero {
-        id: "dimas",
-        name: "Димасик",
-        desc: "Организатор предыдущего конкурса.\nУникальная способность - STAR WARS.",
-        branches: vec![CardBranch::Attack, CardBranch::Crit],
-        hp: 1350.0,
-        mana_regen: 11.0,
-        attack: 35.0,
-        attack_speed: 0.71,
-        crit: 0.17,
-        evasion: 0.09,
-        abils: vec![
-            Ability::<Attack>::new().into(),
-            Ability::<RegenMana>::new().into(),
-            Ability::<StarWars>::new().into(),
-        ],
-    }
+// serde implicitly borrows `&str` fields from the input, the alias opts out of that
+pub type Id = &'static str;
+
+pub fn deserialize_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Id, D::Error> {
+    Hero::deserialize(deserializer).map(|hero| hero.id)
+}
+
+pub fn by_id(id: &str) -> Option<Hero> {
+    def(id).map(Hero::new)
+}
+
+pub fn all() -> Vec<Hero> {
+    defs().iter().map(Hero::new).collect()
 }
 
-pub fn duck() -> Hero {
-    Hero {
-        id: "duck",
-        name: "Утка",
-        desc: "Умеет делиться пополам.\nУникальная способность - HALVE.",
-        branches: vec![CardBranch::Hp, CardBranch::Regen],
-        hp: 1200.0,
-        mana_regen: 10.0,
-        attack: 24.0,
-        attack_speed: 0.95,
-        crit: 0.15,
-        evasion: 0.14,
-        abils: vec![
-            Ability::<Attack>::new().into(),
-            Ability::<RegenMana>::new().into(),
-            Ability::<Halve>::new().into(),
-        ],
+impl Hero {
+    pub fn new(def: &'static HeroDef) -> Self {
+        Self {
+            id: &def.id,
+            name: &def.name,
+            desc: &def.desc,
+            branches: def.branches.clone(),
+            hp: def.hp,
+            mana_regen: def.mana_regen,
+            attack: def.attack,
+            attack_speed: def.attack_speed,
+            crit: def.crit,
+            evasion: def.evasion,
+            armor: def.armor,
+            magic_resist: def.magic_resist,
+            abils: def.abilities.clone(),
+        }
     }
 }
 
-pub fn kisanya() -> Hero {
-    Hero {
-        id: "kisanya",
-        name: "Кисаня",
-        desc: "Обязательно пройдёт твою игру на стриме.\nУникальная способность - небесный лазер.",
-        branches: vec![CardBranch::Crit, CardBranch::Evasion],
-        hp: 1100.0,
-        mana_regen: 9.0,
-        attack: 22.0,
-        attack_speed: 1.11,
-        crit: 0.15,
-        evasion: 0.12,
-        abils: vec![
-            Ability::<Attack>::new().into(),
-            Ability::<RegenMana>::new().into(),
-            Ability::<Beam<false, 300>>::new().into(),
-        ],
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShowoffPart {
+    pub track: String,
+    pub repeat: u32,
+    pub speed: f32,
+    pub wait_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationsDef {
+    pub idle: String,
+    pub attack: String,
+    pub attack_frames: u32,
+    pub win: String,
+    pub lose: String,
+    pub showoff: Vec<ShowoffPart>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloseUpDef {
+    pub origin: (f32, f32, f32),
+    pub target: (f32, f32, f32),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectileDef {
+    pub offset: (f32, f32, f32),
+    pub color: (f32, f32, f32),
+    pub radius: f32,
+}
+
+/// Plugin that spawns and animates the model, the named ones add the hero's own effects
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum Spawner {
+    #[default]
+    Generic,
+    Nulch,
+    Rasp,
+    DTyan,
+    Dimas,
+    Duck,
+    Kisanya,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelDef {
+    #[serde(default)]
+    pub spawner: Spawner,
+    pub gltf: String,
+    pub scale: f32,
+    pub camera: (f32, f32, f32),
+    /// camera of the hero's page, `camera` turned aside when not set
+    #[serde(default)]
+    pub close_up: Option<CloseUpDef>,
+    pub shoot_sound: String,
+    pub projectile: ProjectileDef,
+    pub animations: AnimationsDef,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeroDef {
+    pub id: String,
+    /// place on the hero select wheel
+    pub order: u32,
+    pub name: String,
+    pub desc: String,
+    pub branches: Vec<CardBranch>,
+    pub hp: f32,
+    pub mana_regen: f32,
+    pub attack: f32,
+    pub attack_speed: f32,
+    pub crit: f32,
+    pub evasion: f32,
+    #[serde(default)]
+    pub armor: f32,
+    #[serde(default)]
+    pub magic_resist: f32,
+    pub abilities: Vec<AbilityDef>,
+    pub model: ModelDef,
+}
+
+pub fn load() -> Result<Vec<HeroDef>, Box<dyn Error>> {
+    let mut defs: Vec<HeroDef> = data::load_dir("heroes")?;
+    defs.sort_by_key(|def| def.order);
+    for (i, def) in defs.iter().enumerate() {
+        if defs[..i].iter().any(|d| d.id == def.id) {
+            return Err(format!("duplicate hero {}", def.id).into());
+        }
     }
+    Ok(defs)
+}
+
+pub fn defs() -> &'static [HeroDef] {
+    static DEFS: OnceLock<Vec<HeroDef>> = OnceLock::new();
+    DEFS.get_or_init(|| load().unwrap_or_else(|err| panic!("failed to load heroes: {err}")))
+}
+
+pub fn def(id: &str) -> Option<&'static HeroDef> {
+    defs().iter().find(|def| def.id == id)
 }
@@ -0,0 +1,150 @@
+use std::{error::Error, fmt, sync::OnceLock};
+
+use rand::{seq::SliceRandom, Rng};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    card::{def::EffectKind, effect::CardEffect},
+    data,
+    effect::Effect,
+};
+
+/// Equipment dropped as a round reward, finished items are combined from two components
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemDef {
+    pub id: String,
+    pub name: String,
+    pub desc: String,
+    pub value: f32,
+    pub effect: EffectKind,
+    /// empty for components, which are the only items that drop
+    #[serde(default)]
+    pub components: Vec<String>,
+}
+
+#[derive(Clone, Copy)]
+pub struct Item(&'static ItemDef);
+
+impl Item {
+    pub fn id(&self) -> &'static str {
+        &self.0.id
+    }
+
+    pub fn name(&self) -> &'static str {
+        &self.0.name
+    }
+
+    pub fn desc(&self) -> &'static str {
+        &self.0.desc
+    }
+
+    pub fn is_component(&self) -> bool {
+        self.0.components.is_empty()
+    }
+
+    pub fn effect(&self) -> Box<dyn Effect> {
+        CardEffect::new(self.0.effect, self.0.value).into()
+    }
+}
+
+impl fmt::Debug for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Item").field(&self.id()).finish()
+    }
+}
+
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+// items are stored by id and looked up in the loaded definitions
+impl Serialize for Item {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.id().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        by_id(&id).ok_or_else(|| de::Error::custom(format!("unknown item {id}")))
+    }
+}
+
+pub fn load() -> Result<Vec<ItemDef>, Box<dyn Error>> {
+    let defs: Vec<ItemDef> = data::load_dir("items")?;
+    for (i, def) in defs.iter().enumerate() {
+        if defs[..i].iter().any(|d| d.id == def.id) {
+            return Err(format!("duplicate item {}", def.id).into());
+        }
+        if def.components.is_empty() {
+            continue;
+        }
+        if def.components.len() != 2 {
+            return Err(format!("item {} must have two components", def.id).into());
+        }
+        for id in &def.components {
+            if !defs.iter().any(|d| d.id == *id && d.components.is_empty()) {
+                return Err(format!("item {} has unknown component {id}", def.id).into());
+            }
+        }
+    }
+    Ok(defs)
+}
+
+pub fn defs() -> &'static [ItemDef] {
+    static DEFS: OnceLock<Vec<ItemDef>> = OnceLock::new();
+    DEFS.get_or_init(|| load().unwrap_or_else(|err| panic!("failed to load items: {err}")))
+}
+
+pub fn by_id(id: &str) -> Option<Item> {
+    defs().iter().find(|def| def.id == id).map(Item)
+}
+
+/// Finished item made of the two components, in any order
+pub fn recipe(first: Item, second: Item) -> Option<Item> {
+    defs()
+        .iter()
+        .find(|def| {
+            let [a, b] = def.components.as_slice() else {
+                return false;
+            };
+            (a == first.id() && b == second.id()) || (a == second.id() && b == first.id())
+        })
+        .map(Item)
+}
+
+/// Distinct random components offered as a reward
+pub fn reward(n: usize, rng: &mut impl Rng) -> Vec<Item> {
+    let components = defs()
+        .iter()
+        .filter(|def| def.components.is_empty())
+        .map(Item)
+        .collect::<Vec<_>>();
+    components.choose_multiple(rng, n).copied().collect()
+}
+
+#[test]
+fn items() {
+    use super::card::{self, set};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    assert!(defs().iter().all(
+        |def| card::by_id(&def.id).is_none() && set::defs().iter().all(|set| set.id != def.id)
+    ));
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let choice = reward(3, &mut rng);
+    assert_eq!(choice.len(), 3);
+    assert!(choice.iter().all(Item::is_component));
+
+    let sword = by_id("sword").unwrap();
+    let gloves = by_id("gloves").unwrap();
+    assert_eq!(recipe(sword, gloves), recipe(gloves, sword));
+    assert!(recipe(sword, gloves).is_some_and(|item| !item.is_component()));
+    for def in defs() {
+        Item(def).effect();
+    }
+}
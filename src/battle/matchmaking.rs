@@ -0,0 +1,167 @@
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+use super::player::Player;
+
+// a repeat costs more than any hp difference
+const REPEAT_COST: u32 = 1000;
+
+/// Pairs players so that the same two meet as rarely as possible
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Matchmaking {
+    /// round and both players of every fight so far
+    meetings: Vec<(u32, String, String)>,
+    /// players who were left without a pair
+    byes: Vec<String>,
+    /// order for the next round, neighbours fight each other and the odd one is last
+    next: Vec<String>,
+}
+
+impl Matchmaking {
+    /// Opponents the player has fought, oldest first
+    pub fn history<'a>(&'a self, id: &'a str) -> impl Iterator<Item = (u32, &'a str)> + 'a {
+        self.meetings
+            .iter()
+            .filter_map(move |(round, a, b)| match id {
+                id if id == a => Some((*round, b.as_str())),
+                id if id == b => Some((*round, a.as_str())),
+                _ => None,
+            })
+    }
+
+    /// Planned opponent, `None` for the odd player out or a player out of the game
+    pub fn next_opponent(&self, id: &str) -> Option<&str> {
+        let index = self.next.iter().position(|p| p == id)?;
+        let other = match index % 2 {
+            0 => index + 1,
+            _ => index - 1,
+        };
+        self.next.get(other).map(String::as_str)
+    }
+
+    fn met(&self, a: &str, b: &str) -> u32 {
+        self.meetings
+            .iter()
+            .filter(|(_, x, y)| (x == a && y == b) || (x == b && y == a))
+            .count() as u32
+    }
+
+    /// Picks the pairing with the fewest repeats, then with the closest hp if asked to
+    pub fn plan(&mut self, players: &[Player], by_hp: bool, rng: &mut impl Rng) {
+        let mut alive = players.iter().filter(|p| p.hp > 0).collect::<Vec<_>>();
+        // ties are broken at random
+        alive.shuffle(rng);
+        let pair = |a: usize, b: usize| {
+            let (a, b) = (alive[a], alive[b]);
+            let hp = match by_hp {
+                true => a.hp.abs_diff(b.hp),
+                false => 0,
+            };
+            self.met(a.hero.id, b.hero.id) * REPEAT_COST + hp
+        };
+        let bye = |a: usize| {
+            self.byes
+                .iter()
+                .filter(|id| *id == alive[a].hero.id)
+                .count() as u32
+                * REPEAT_COST
+        };
+        let (_, order) = matching(&(0..alive.len()).collect::<Vec<_>>(), &pair, &bye);
+        self.next = order
+            .into_iter()
+            .map(|i| alive[i].hero.id.to_string())
+            .collect();
+    }
+
+    /// Order of the alive players for the round, planning again if somebody changed
+    pub fn order(&mut self, players: &[Player], by_hp: bool, rng: &mut impl Rng) -> Vec<String> {
+        let alive = players.iter().filter(|p| p.hp > 0).count();
+        if self.next.len() != alive
+            || self
+                .next
+                .iter()
+                .any(|id| !players.iter().any(|p| p.hero.id == id && p.hp > 0))
+        {
+            self.plan(players, by_hp, rng);
+        }
+        self.next.clone()
+    }
+
+    pub fn record(&mut self, round: u32, a: &str, b: &str) {
+        self.meetings.push((round, a.to_string(), b.to_string()));
+    }
+
+    pub fn record_bye(&mut self, id: &str) {
+        self.byes.push(id.to_string());
+    }
+}
+
+/// Cheapest split into pairs, the odd player goes last
+fn matching(
+    players: &[usize],
+    pair: &dyn Fn(usize, usize) -> u32,
+    bye: &dyn Fn(usize) -> u32,
+) -> (u32, Vec<usize>) {
+    let Some((&first, rest)) = players.split_first() else {
+        return (0, vec![]);
+    };
+    let mut best = (u32::MAX, vec![]);
+    if players.len() % 2 == 1 {
+        let (cost, mut order) = matching(rest, pair, bye);
+        order.push(first);
+        best = (cost + bye(first), order);
+    }
+    for (i, &second) in rest.iter().enumerate() {
+        let others = rest[..i]
+            .iter()
+            .chain(&rest[i + 1..])
+            .copied()
+            .collect::<Vec<_>>();
+        let (cost, order) = matching(&others, pair, bye);
+        let cost = cost + pair(first, second);
+        if cost < best.0 {
+            best = (cost, [vec![first, second], order].concat());
+        }
+    }
+    best
+}
+
+#[test]
+fn matchmaking() {
+    use super::hero;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut players = hero::all()
+        .into_iter()
+        .take(4)
+        .map(Player::new)
+        .collect::<Vec<_>>();
+    let mut matchmaking = Matchmaking::default();
+
+    // four players meet everyone before anyone fights twice
+    for round in 1..=3 {
+        matchmaking.plan(&players, false, &mut rng);
+        for pair in matchmaking.next.clone().chunks(2) {
+            matchmaking.record(round, &pair[0], &pair[1]);
+        }
+    }
+    let id = players[0].hero.id;
+    let mut opponents = matchmaking.history(id).map(|(_, o)| o).collect::<Vec<_>>();
+    opponents.sort();
+    opponents.dedup();
+    assert_eq!(opponents.len(), 3);
+
+    players[1].hp = 1;
+    players[2].hp = 2;
+    matchmaking.plan(&players, true, &mut rng);
+    assert_eq!(
+        matchmaking.next_opponent(players[1].hero.id),
+        Some(players[2].hero.id)
+    );
+
+    players[3].hp = 0;
+    matchmaking.plan(&players, false, &mut rng);
+    assert_eq!(matchmaking.next.len(), 3);
+    assert_eq!(matchmaking.next_opponent(&matchmaking.next[2]), None);
+}
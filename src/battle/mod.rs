@@ -1,46 +1,36 @@
 pub mod ability;
+pub mod bot;
 pub mod card;
+pub mod combat_log;
+pub mod creep;
+pub mod data;
 mod effect;
 pub mod fight;
+pub mod ghost;
 pub mod hero;
+pub mod item;
+pub mod matchmaking;
 pub mod modifier;
 pub mod player;
-
-use std::cmp::Reverse;
-
-use card::agility_capsule::AgilityCapsule;
-use card::agility_web::AgilityWeb;
-use card::capture_maneuver::CaptureManeuver;
-use card::combat_medkit::CombatMedkit;
-use card::energy_drain::EnergyDrain;
-use card::energy_source::EnergySource;
-use card::exhaustion::Exhaustion;
-use card::healing_drone::HealingDrone;
-use card::healing_flow::HealingFlow;
-use card::hero_might::HeroMight;
-use card::illness::Illness;
-use card::life_essence::LifeEssence;
-use card::life_symbiosis::LifeSymbiosis;
-use card::lucky_bullet::LuckyBullet;
-use card::magic_generator::MagicGenerator;
-use card::mana_crystal::ManaCrystal;
-use card::plasma_charge::PlasmaCharge;
-use card::plasma_strike::PlasmaStrike;
-use card::power_drainer::PowerDrainer;
-use card::precision_hit::PrecisionHit;
-use card::shadow_bastion::ShadowBastion;
-use card::shadow_caster::ShadowCaster;
-use card::shadow_dance::ShadowDance;
-use card::shock_wave::ShockWave;
-use card::shooter_luck::ShooterLuck;
-use card::sign_of_misfortune::SignOfMisfortune;
-use card::symbol_of_luck::SymbolOfLuck;
-use card::{Card, CardBranch, CardInfo, CardOps};
-use effect::{Effect, HasEffect};
+pub mod replay;
+pub mod ruleset;
+pub mod save;
+pub mod status;
+
+use bot::{BotAction, Difficulty, Strategy};
+use card::{CardBranch, CardOps};
+use creep::{CreepDef, Reward};
 use fight::{Fight, FightCapture, Owner};
+use matchmaking::Matchmaking;
 use player::Player;
+use ruleset::{OddPlayer, Ruleset};
 use rand::prelude::SliceRandom;
-use rand::{random, thread_rng};
+use rand::rngs::StdRng;
+use rand::{random, Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+const MAX_BOT_ACTIONS: usize = 100;
 
 struct CardsPool {
     players: usize,
@@ -55,28 +45,17 @@ impl CardsPool {
         }
     }
 
-    fn add_card<C>(&mut self)
-    where
-        C: CardInfo + Effect + 'static,
-        Card<C>: HasEffect,
-    {
+    fn add_card(&mut self, card: Box<dyn CardOps>) {
         for _ in 0..self.players {
-            for _ in 0..C::max_level() {
-                self.cards.push(Box::new(Card::<C>::new(
-                    C::id(),
-                    C::branches(),
-                    C::max_level(),
-                    C::name(),
-                    C::desc(),
-                    C::cost(),
-                )));
+            for _ in 0..card.max_level() {
+                self.cards.push(card.clone());
             }
         }
     }
 
-    fn take(&mut self, player: &Player, n: usize) -> Vec<Box<dyn CardOps>> {
+    fn take(&mut self, player: &Player, n: usize, rng: &mut ChaCha12Rng) -> Vec<Box<dyn CardOps>> {
         let mut cards: Vec<Box<dyn CardOps>> = vec![];
-        self.cards.shuffle(&mut rand::thread_rng());
+        self.cards.shuffle(rng);
         self.cards.retain(|card| {
             let skip = cards.len() >= n
                 || cards.iter().find(|c| c.id() == card.id()).map_or_else(
@@ -106,102 +85,175 @@ pub struct Battle {
     pub players: Vec<Player>,
     next_players: Vec<Player>,
     cards_pool: CardsPool,
-    cards_locked: bool,
+    /// the rng was seeded with it when the battle began
+    seed: u64,
+    rng: ChaCha12Rng,
     pub round: u32,
+    pub difficulty: Difficulty,
+    pub ruleset: Ruleset,
+    pub matchmaking: Matchmaking,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RoundCapture {
     Fight {
-        player1: &'static str,
-        player2: &'static str,
+        #[serde(deserialize_with = "hero::deserialize_id")]
+        player1: hero::Id,
+        #[serde(deserialize_with = "hero::deserialize_id")]
+        player2: hero::Id,
         winner: Owner,
         fight_capture: FightCapture,
     },
-    Skip(&'static str),
+    Skip(#[serde(deserialize_with = "hero::deserialize_id")] hero::Id),
 }
 
 impl Battle {
     pub fn new(players: Vec<Player>) -> Self {
+        Self::with_seed(players, random())
+    }
+
+    pub fn with_seed(players: Vec<Player>, seed: u64) -> Self {
+        Self::with_ruleset(players, seed, Ruleset::default())
+    }
+
+    pub fn with_ruleset(players: Vec<Player>, seed: u64, ruleset: Ruleset) -> Self {
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
         let mut cards_pool = CardsPool::new(players.len());
-        cards_pool.add_card::<ShockWave>();
-        cards_pool.add_card::<PowerDrainer>();
-        cards_pool.add_card::<EnergySource>();
-        cards_pool.add_card::<CombatMedkit>();
-        cards_pool.add_card::<PlasmaStrike>();
-        cards_pool.add_card::<PrecisionHit>();
-        cards_pool.add_card::<CaptureManeuver>();
-        cards_pool.add_card::<HealingFlow>();
-        cards_pool.add_card::<Exhaustion>();
-        cards_pool.add_card::<HealingDrone>();
-        cards_pool.add_card::<MagicGenerator>();
-        cards_pool.add_card::<LuckyBullet>();
-        cards_pool.add_card::<AgilityCapsule>();
-        cards_pool.add_card::<LifeEssence>();
-        cards_pool.add_card::<Illness>();
-        cards_pool.add_card::<LifeSymbiosis>();
-        cards_pool.add_card::<HeroMight>();
-        cards_pool.add_card::<ShadowBastion>();
-        cards_pool.add_card::<ManaCrystal>();
-        cards_pool.add_card::<EnergyDrain>();
-        cards_pool.add_card::<PlasmaCharge>();
-        cards_pool.add_card::<ShadowCaster>();
-        cards_pool.add_card::<SymbolOfLuck>();
-        cards_pool.add_card::<SignOfMisfortune>();
-        cards_pool.add_card::<ShooterLuck>();
-        cards_pool.add_card::<ShadowDance>();
-        cards_pool.add_card::<AgilityWeb>();
+        for card in card::all() {
+            cards_pool.add_card(card);
+        }
 
-        Self {
+        let mut battle = Self {
             players: players
                 .into_iter()
                 .map(|mut player| {
-                    player.reserve_cards(cards_pool.take(&player, 3));
+                    player.reserve_cards(cards_pool.take(&player, 3, &mut rng));
                     player
                 })
                 .collect(),
             next_players: vec![],
             cards_pool,
-            cards_locked: false,
+            seed,
+            rng,
             round: 1,
-        }
+            difficulty: Difficulty::default(),
+            ruleset,
+            matchmaking: Matchmaking::default(),
+        };
+        battle.plan();
+        battle
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    fn plan(&mut self) {
+        self.matchmaking
+            .plan(&self.players, self.ruleset.matchmaking_by_hp, &mut self.rng);
+    }
+
+    /// Creep everyone fights this round, if it's a creep round
+    pub fn creep_round(&self) -> Option<&'static CreepDef> {
+        creep::for_round(self.round).filter(|_| self.ruleset.creep_rounds.contains(&self.round))
     }
 
     pub fn round(&mut self) -> Vec<RoundCapture> {
-        self.next_players = self
-            .players
+        let order =
+            self.matchmaking
+                .order(&self.players, self.ruleset.matchmaking_by_hp, &mut self.rng);
+        self.next_players = order
             .iter()
-            .filter(|p| p.hp > 0)
-            .map(|p| p.clone())
+            .map(|id| self.players.iter().find(|p| p.hero.id == id).unwrap().clone())
             .collect::<Vec<_>>();
-        self.next_players.shuffle(&mut thread_rng());
 
         let alive = self.next_players.len();
 
         self.next_players
-            .extend(self.players.iter().filter(|p| p.hp <= 0).map(|p| p.clone()));
+            .extend(self.players.iter().filter(|p| p.hp <= 0).cloned());
 
+        let creep_round = self.creep_round();
         let (alive, dead) = self.next_players.split_at_mut(alive);
+        let rng = &mut self.rng;
+        let ruleset = &self.ruleset;
+        // on creep rounds everyone fights alone
+        let size = match creep_round {
+            Some(_) => 1,
+            None => 2,
+        };
+
+        // the odd player out, who is the last one, fights a creep or a copy of someone else
+        let creep = creep_round.or_else(|| {
+            creep::for_round(self.round).filter(|_| ruleset.odd_player == OddPlayer::Creep)
+        });
+        let odd = alive.len() % 2 == 1 && size == 2;
+        let ghost = match odd && ruleset.odd_player == OddPlayer::Ghost {
+            true => alive[..alive.len() - 1].choose(rng).map(ghost::of),
+            false => None,
+        };
 
         let rounds = alive
-            .chunks_mut(2)
-            .into_iter()
-            .map(|pair| {
-                if let [p1, p2] = pair {
-                    let (winner, fight_capture) = Fight::new(p1, p2).run();
+            .chunks_mut(size)
+            .map(|pair| match (pair, creep) {
+                ([p1, p2], _) => {
+                    let (winner, fight_capture) = Fight::new(p1, p2, rng.gen()).run();
+                    match winner {
+                        Owner::Fighter1 => Self::payout(ruleset, p1, p2),
+                        Owner::Fighter2 => Self::payout(ruleset, p2, p1),
+                    }
                     RoundCapture::Fight {
                         player1: p1.hero.id,
                         player2: p2.hero.id,
                         winner,
                         fight_capture,
                     }
-                } else {
-                    RoundCapture::Skip(pair[0].hero.id)
                 }
+                ([player], _) if ghost.is_some() => {
+                    let mut ghost = ghost.clone().unwrap();
+                    let (winner, fight_capture) = Fight::new(player, &ghost, rng.gen()).run();
+                    match winner {
+                        Owner::Fighter1 => Self::payout(ruleset, player, &mut ghost),
+                        Owner::Fighter2 => Self::payout(ruleset, &mut ghost, player),
+                    }
+                    RoundCapture::Fight {
+                        player1: player.hero.id,
+                        player2: ghost.hero.id,
+                        winner,
+                        fight_capture,
+                    }
+                }
+                ([player], Some(creep)) => {
+                    let monster = creep.player(ruleset);
+                    let (winner, fight_capture) = Fight::new(player, &monster, rng.gen()).run();
+                    Self::creep_payout(ruleset, creep, player, winner == Owner::Fighter1);
+                    RoundCapture::Fight {
+                        player1: player.hero.id,
+                        player2: monster.hero.id,
+                        winner,
+                        fight_capture,
+                    }
+                }
+                (pair, _) => RoundCapture::Skip(pair[0].hero.id),
             })
-            .chain(dead.into_iter().map(|p| RoundCapture::Skip(p.hero.id)))
+            .chain(dead.iter().map(|p| RoundCapture::Skip(p.hero.id)))
             .collect::<Vec<_>>();
 
+        for capture in &rounds {
+            let RoundCapture::Fight {
+                player1, player2, ..
+            } = capture
+            else {
+                continue;
+            };
+            if ghost::def(player2).is_some()
+                || (creep::def(player2).is_some() && creep_round.is_none())
+            {
+                self.matchmaking.record_bye(player1);
+            } else if creep::def(player2).is_none() {
+                self.matchmaking.record(self.round, player1, player2);
+            }
+        }
+
         rounds
     }
 
@@ -211,17 +263,51 @@ impl Battle {
             .iter()
             .map(|player| {
                 let mut player = player.clone();
-                if !self.cards_locked {
-                    Self::reroll_free(&mut self.cards_pool, &mut player);
+                if !player.cards_locked {
+                    Self::reroll_free(&mut self.cards_pool, &mut player, &mut self.rng);
                 }
                 player
             })
             .collect();
         self.next_players.clear();
         self.round += 1;
+        if (self.round - 1).is_multiple_of(self.ruleset.item_every) {
+            for player in self.players.iter_mut().filter(|p| p.hp > 0) {
+                player.item_choice = item::reward(3, &mut self.rng);
+            }
+        }
+        self.plan();
+    }
+
+    fn payout(ruleset: &Ruleset, winner: &mut Player, loser: &mut Player) {
+        winner.money += ruleset.interest(winner.money);
+        loser.money += ruleset.interest(loser.money);
+        winner.money += ruleset.income;
+        loser.money += ruleset.income;
+
+        winner.money += ruleset.win_bonus;
+        loser.money += winner.attack * ruleset.loss_bonus_per_attack;
+
+        loser.hp = (loser.hp - winner.attack as i32).max(0);
+
+        loser.attack = ruleset.start_attack;
+        winner.attack = (winner.attack + 1).min(ruleset.max_attack);
     }
 
-    fn reroll_free(cards_pool: &mut CardsPool, player: &mut Player) {
+    fn creep_payout(ruleset: &Ruleset, creep: &CreepDef, player: &mut Player, won: bool) {
+        player.money += ruleset.interest(player.money);
+        player.money += ruleset.income;
+        if !won {
+            player.hp = (player.hp - creep.damage as i32).max(0);
+            return;
+        }
+        match creep.reward {
+            Reward::Money(money) => player.money += money,
+            Reward::FreeCard => player.free_cards += 1,
+        }
+    }
+
+    fn reroll_free(cards_pool: &mut CardsPool, player: &mut Player, rng: &mut ChaCha12Rng) {
         let mut cards = vec![];
         cards.append(&mut player.cards_reserved);
         cards_pool.refill(
@@ -230,7 +316,7 @@ impl Battle {
                 .filter_map(|(active, card)| if active { Some(card) } else { None })
                 .collect(),
         );
-        player.reserve_cards(cards_pool.take(&player, 3));
+        player.reserve_cards(cards_pool.take(player, 3, rng));
     }
 
     pub fn reroll(&mut self, id: &str) {
@@ -239,9 +325,9 @@ impl Battle {
             .iter_mut()
             .find(|player| player.hero.id == id)
             .unwrap();
-        if player.money >= 20 {
-            player.money -= 20;
-            Self::reroll_free(&mut self.cards_pool, player);
+        if player.money >= self.ruleset.reroll_cost {
+            player.money -= self.ruleset.reroll_cost;
+            Self::reroll_free(&mut self.cards_pool, player, &mut self.rng);
         }
     }
 
@@ -259,16 +345,68 @@ impl Battle {
             .iter()
             .fold(false, |acc, (a, _)| acc || *a)
         {
-            Self::reroll_free(&mut self.cards_pool, player);
+            Self::reroll_free(&mut self.cards_pool, player, &mut self.rng);
         }
     }
 
-    pub fn is_cards_locked(&self) -> bool {
-        self.cards_locked
+    /// Refunds part of the card's price and puts its copies back into the pool
+    pub fn sell_card(&mut self, id: &str, index: usize) {
+        let player = self
+            .players
+            .iter_mut()
+            .find(|player| player.hero.id == id)
+            .unwrap();
+
+        let refund = player.refund(index, &self.ruleset);
+        let Some(card) = player.sell_card(index) else {
+            return;
+        };
+        player.money += refund;
+        self.cards_pool
+            .refill((0..card.level()).map(|_| card.clone()).collect());
     }
 
-    pub fn set_cards_locked(&mut self, locked: bool) {
-        self.cards_locked = locked
+    pub fn pick_item(&mut self, id: &str, index: usize) {
+        let slots = self.ruleset.item_slots;
+        self.player_by_id(id).pick_item(index, slots);
+    }
+
+    pub fn combine_items(&mut self, id: &str, first: usize, second: usize) {
+        self.player_by_id(id).combine_items(first, second);
+    }
+
+    /// Every bot prefers a component that completes an item, then combines all it can
+    fn ai_items(&mut self, id: &str) {
+        let slots = self.ruleset.item_slots;
+        let player = self.player_by_id(id);
+        let completes = player.item_choice.iter().position(|choice| {
+            player
+                .items
+                .iter()
+                .any(|item| item::recipe(*item, *choice).is_some())
+        });
+        player.pick_item(completes.unwrap_or(0), slots);
+        loop {
+            let items = &player.items;
+            let pair = (0..items.len())
+                .flat_map(|first| (first + 1..items.len()).map(move |second| (first, second)))
+                .find(|(first, second)| item::recipe(items[*first], items[*second]).is_some());
+            let Some((first, second)) = pair else {
+                break;
+            };
+            player.combine_items(first, second);
+        }
+    }
+
+    pub fn is_cards_locked(&self, id: &str) -> bool {
+        self.players
+            .iter()
+            .find(|player| player.hero.id == id)
+            .is_some_and(|player| player.cards_locked)
+    }
+
+    pub fn set_cards_locked(&mut self, id: &str, locked: bool) {
+        self.player_by_id(id).cards_locked = locked
     }
 
     pub fn branch_max(&self, branch: &CardBranch) -> u32 {
@@ -296,69 +434,202 @@ impl Battle {
             .unwrap()
     }
 
-    // TODO: Super ugly, need to rewrite
-    pub fn ai(&mut self, id: &str) {
-        loop {
-            if self.player_by_id(id).cards_reserved.is_empty() {
-                break;
-            }
-
-            let mut candidates = vec![];
-            for (index, (active, card)) in self
-                .player_by_id(id)
-                .cards_reserved
-                .clone()
-                .iter()
-                .enumerate()
-            {
-                if !active {
-                    continue;
-                }
+    /// Hands every player a random strategy from the battle's rng, so the seed reproduces the bots
+    pub fn pick_strategies(&mut self, strategies: &[Strategy]) {
+        for player in &mut self.players {
+            player.strategy = *strategies.choose(&mut self.rng).unwrap();
+        }
+    }
 
-                let mut score = 0;
-                for b1 in &card.branches() {
-                    for b2 in &self.player_by_id(id).hero.branches {
-                        if b1 == b2 {
-                            score += 1u32;
-                        }
+    /// Lets the player's strategy shop for them
+    pub fn ai(&mut self, id: &str) {
+        self.ai_items(id);
+        let difficulty = self.difficulty;
+        let bot = self.player_by_id(id).strategy.bot(difficulty);
+        let mut rng = StdRng::seed_from_u64(self.rng.gen());
+        let mut rerolls = 0;
+        for _ in 0..MAX_BOT_ACTIONS {
+            let me = self.players.iter().find(|p| p.hero.id == id).unwrap();
+            match bot.act(self, me, &mut rng) {
+                Some(BotAction::BuyCard(index)) => {
+                    self.buy_card(id, index);
+                    if rng.gen::<f32>() < difficulty.give_up_chance() {
+                        break;
                     }
                 }
-
-                candidates.push((index, score));
+                Some(BotAction::SellCard(index)) => self.sell_card(id, index),
+                Some(BotAction::Reroll) if rerolls < difficulty.rerolls() => {
+                    rerolls += 1;
+                    self.reroll(id);
+                }
+                Some(BotAction::SetCardsLocked(locked)) if difficulty.locks_shop() => {
+                    self.set_cards_locked(id, locked)
+                }
+                _ => break,
             }
+        }
+    }
+}
 
-            candidates.sort_by_key(|(_, score)| Reverse(*score));
+#[test]
+fn battle() {
+    let mut battle = Battle::new(hero::all().into_iter().map(Player::new).collect());
 
-            for (index, score) in candidates {
-                let score = match score {
-                    0 => 0.1,
-                    _ => 1.0,
-                };
-                if self.player_by_id(id).money > 1100 && random::<f32>() < score {
-                    self.buy_card(id, index);
-                }
-            }
+    println!("{:#?}", battle.round());
+}
 
-            if self.player_by_id(id).money >= 1120 {
-                self.reroll(id);
-            } else {
-                break;
+#[test]
+fn battle_seed() {
+    let simulate = |seed| {
+        let mut battle = Battle::with_seed(hero::all().into_iter().map(Player::new).collect(), seed);
+        battle.pick_strategies(&Strategy::IN_GAME);
+        let mut log = vec![];
+        for _ in 0..5 {
+            for id in battle.players.iter().map(|p| p.hero.id).collect::<Vec<_>>() {
+                battle.ai(id);
             }
+            log.push(format!("{:?}", battle.round()));
+            battle.apply();
         }
+        log
+    };
+
+    assert_eq!(simulate(42), simulate(42));
+}
+
+#[test]
+fn sell_card() {
+    let mut battle = Battle::with_seed(hero::all().into_iter().map(Player::new).collect(), 3);
+    let pool = battle.cards_pool.cards.len();
+    battle.player_by_id("rasp").money = 1000;
+    battle.buy_card("rasp", 0);
+    let money = battle.player_by_id("rasp").money;
+    let cost = battle.player_by_id("rasp").cards[0].cost();
+
+    battle.sell_card("rasp", 0);
+    let player = battle.player_by_id("rasp");
+    assert!(player.cards.is_empty());
+    assert_eq!(player.money, money + cost / 2);
+    assert_eq!(player.cards_reserved[0].1.level(), 0);
+    assert_eq!(battle.cards_pool.cards.len(), pool + 1);
+
+    // a creep reward is not worth any money
+    battle.player_by_id("rasp").free_cards = 1;
+    battle.buy_card("rasp", 1);
+    assert_eq!(battle.player_by_id("rasp").cards.len(), 1);
+    let money = battle.player_by_id("rasp").money;
+    battle.sell_card("rasp", 0);
+    let player = battle.player_by_id("rasp");
+    assert!(player.cards.is_empty());
+    assert_eq!(player.money, money);
+}
+
+#[test]
+fn items() {
+    let ruleset = Ruleset {
+        item_every: 1,
+        item_slots: 2,
+        ..Ruleset::default()
+    };
+    let players = hero::all().into_iter().map(Player::new).collect();
+    let mut battle = Battle::with_ruleset(players, 5, ruleset);
+    for _ in 0..3 {
+        battle.round();
+        battle.apply();
+        assert!(battle.players.iter().all(|p| p.item_choice.len() == 3));
+        battle.pick_item("rasp", 0);
     }
+    let player = battle.player_by_id("rasp");
+    assert_eq!(player.items.len(), 2);
+    assert_eq!(player.item_choice.len(), 3);
+
+    player.items = vec![item::by_id("sword").unwrap(), item::by_id("belt").unwrap()];
+    battle.combine_items("rasp", 1, 0);
+    assert_eq!(
+        battle.player_by_id("rasp").items,
+        vec![item::by_id("bloodthirster").unwrap()]
+    );
 }
 
 #[test]
-fn battle() {
-    use hero::{dimas, dtyan, duck, kisanya, nulch, rasp};
-    let mut battle = Battle::new(vec![
-        Player::new(nulch()),
-        Player::new(rasp()),
-        Player::new(dtyan()),
-        Player::new(dimas()),
-        Player::new(duck()),
-        Player::new(kisanya()),
-    ]);
+fn creep_rounds() {
+    let ruleset = Ruleset {
+        creep_rounds: vec![1],
+        ..Ruleset::default()
+    };
+    let players = hero::all().into_iter().take(3).map(Player::new).collect();
+    let mut battle = Battle::with_ruleset(players, 7, ruleset);
+    let creep = creep::for_round(1).unwrap();
+    let captures = battle.round();
+    assert_eq!(captures.len(), 3);
+    assert!(captures.iter().all(|capture| matches!(
+        capture,
+        RoundCapture::Fight { player2, .. } if *player2 == creep.id
+    )));
+    battle.apply();
+
+    // the odd player out fights a ghost of someone else on other rounds
+    let captures = battle.round();
+    assert_eq!(captures.len(), 2);
+    let RoundCapture::Fight {
+        player1: copied,
+        player2: other,
+        ..
+    } = captures[0]
+    else {
+        panic!("pair skipped");
+    };
+    let RoundCapture::Fight { player2, .. } = captures[1] else {
+        panic!("odd player skipped");
+    };
+    let ghost = ghost::def(player2).unwrap();
+    assert!(ghost.hero.id == copied || ghost.hero.id == other);
+
+    // or a creep, if the ruleset says so
+    let ruleset = Ruleset {
+        odd_player: OddPlayer::Creep,
+        ..Ruleset::default()
+    };
+    let players = hero::all().into_iter().take(3).map(Player::new).collect();
+    let mut battle = Battle::with_ruleset(players, 7, ruleset);
+    let captures = battle.round();
+    assert_eq!(captures.len(), 2);
+    assert!(matches!(
+        captures[1],
+        RoundCapture::Fight { player2, .. } if player2 == creep.id
+    ));
+}
 
-    println!("{:#?}", battle.round());
+#[test]
+fn cards_locked() {
+    let reserved = |battle: &Battle, id: &str| {
+        let player = battle.players.iter().find(|p| p.hero.id == id).unwrap();
+        format!("{:?}", player.cards_reserved)
+    };
+
+    let mut battle = (0..)
+        .map(|seed| {
+            let players = hero::all().into_iter().map(Player::new).collect();
+            let mut battle = Battle::with_seed(players, seed);
+            battle.difficulty = Difficulty::Hard;
+            battle.player_by_id("rasp").strategy = Strategy::Economy;
+            // nothing is affordable so the bot holds on to a wanted card
+            battle.player_by_id("rasp").money = 0;
+            battle.ai("rasp");
+            battle
+        })
+        .find(|battle| battle.is_cards_locked("rasp"))
+        .unwrap();
+    let ids = battle.players.iter().map(|p| p.hero.id).collect::<Vec<_>>();
+    let before = ids
+        .iter()
+        .map(|id| reserved(&battle, id))
+        .collect::<Vec<_>>();
+    battle.round();
+    battle.apply();
+
+    for (id, before) in ids.iter().zip(before) {
+        assert_eq!(battle.is_cards_locked(id), *id == "rasp");
+        assert_eq!(reserved(&battle, id) == before, *id == "rasp", "{id}");
+    }
 }
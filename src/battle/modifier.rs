@@ -1,13 +1,34 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+use super::status::{Dispel, StatusDesc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DamageType {
+    Physical,
+    Magical,
+    Pure,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Modifier {
     AffectAttack(f32),
     AffectAttackSpeed(f32),
-    AffectHP(f32),
+    Damage(f32, DamageType),
+    Heal(f32),
+    TrueHeal(f32),
     AffectMaxHP(f32),
     AffectMana(f32),
     AffectUltiAmp(f32),
     AffectCrit(f32),
     AffectEvasion(f32),
+    AffectArmor(f32),
+    AffectMagicResist(f32),
+    AffectDamageReduction(f32),
+    AffectHealReduction(f32),
+    AffectShield(f32),
+    ApplyStatus(StatusDesc),
+    Dispel(Dispel),
+    StatusDamage(f32),
     // markers
     NormalAttack,
     Ulti,
@@ -23,20 +44,20 @@ pub enum Modifier {
     ShootDamageBeam,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Target {
     Myself,
     Enemy,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ValueKind {
     Units,
     Percents,
     Ulti,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModifierDesc {
     pub modifier: Modifier,
     pub target: Target,
@@ -1,29 +1,62 @@
-use bevy_inspector_egui::egui::TextBuffer;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
 
 use super::{
-    card::{CardBranch, CardOps},
+    bot::Strategy,
+    card::{self, CardBranch, CardOps},
     hero::Hero,
+    item::{self, Item},
+    ruleset::Ruleset,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     pub hero: Hero,
     pub money: u32,
     pub attack: u32,
     pub hp: i32,
     pub cards: Vec<Box<dyn CardOps>>,
+    #[serde(deserialize_with = "card::deserialize_reserved")]
     pub cards_reserved: Vec<(bool, Box<dyn CardOps>)>,
+    /// used when the computer plays this hero
+    #[serde(default)]
+    pub strategy: Strategy,
+    #[serde(default)]
+    pub items: Vec<Item>,
+    /// reward waiting for the player to pick one of the items
+    #[serde(default)]
+    pub item_choice: Vec<Item>,
+    /// cards the player can take from the shop without paying
+    #[serde(default)]
+    pub free_cards: u32,
+    /// shop is kept as is for the next round
+    #[serde(default)]
+    pub cards_locked: bool,
+    /// money spent on every owned card by id, free cards sell for nothing
+    #[serde(default)]
+    pub paid: BTreeMap<String, u32>,
 }
 
 impl Player {
     pub fn new(hero: Hero) -> Self {
+        Self::with_ruleset(hero, &Ruleset::default())
+    }
+
+    pub fn with_ruleset(hero: Hero, ruleset: &Ruleset) -> Self {
         Self {
             hero,
-            money: 300,
-            attack: 3,
-            hp: 50,
+            money: ruleset.start_money,
+            attack: ruleset.start_attack,
+            hp: ruleset.start_hp,
             cards: vec![],
             cards_reserved: vec![],
+            strategy: Strategy::default(),
+            items: vec![],
+            item_choice: vec![],
+            free_cards: 0,
+            cards_locked: false,
+            paid: BTreeMap::new(),
         }
     }
 
@@ -31,9 +64,15 @@ impl Player {
         let Some((ref mut active, ref card)) = self.cards_reserved.get_mut(index) else {
             return;
         };
-        if *active && self.money >= card.cost() {
+        let price = match self.free_cards {
+            0 => card.cost(),
+            _ => 0,
+        };
+        if *active && self.money >= price {
             *active = false;
-            self.money -= card.cost();
+            self.money -= price;
+            self.free_cards = self.free_cards.saturating_sub(1);
+            *self.paid.entry(card.id().to_string()).or_default() += price;
             if let Some(card) = self.cards.iter_mut().find(|c| c.id() == card.id()) {
                 card.set_level(card.level() + 1);
             } else {
@@ -44,6 +83,31 @@ impl Player {
         }
     }
 
+    pub fn sell_card(&mut self, index: usize) -> Option<Box<dyn CardOps>> {
+        if index >= self.cards.len() {
+            return None;
+        }
+        let card = self.cards.remove(index);
+        self.paid.remove(card.id());
+        for (_, reserved) in &mut self.cards_reserved {
+            if reserved.id() == card.id() {
+                reserved.set_level(0);
+            }
+        }
+        Some(card)
+    }
+
+    /// What selling the owned card would return
+    pub fn refund(&self, index: usize, ruleset: &Ruleset) -> u32 {
+        let paid = self
+            .cards
+            .get(index)
+            .and_then(|card| self.paid.get(card.id()))
+            .copied()
+            .unwrap_or(0);
+        ruleset.refund(paid)
+    }
+
     pub fn reserve_cards(&mut self, cards: Vec<Box<dyn CardOps>>) {
         self.cards_reserved = cards
             .into_iter()
@@ -58,6 +122,30 @@ impl Player {
             .collect();
     }
 
+    /// Takes the offered item if it fits into a free slot
+    pub fn pick_item(&mut self, index: usize, slots: u32) -> bool {
+        if index >= self.item_choice.len() || self.items.len() >= slots as usize {
+            return false;
+        }
+        let item = self.item_choice[index];
+        self.items.push(item);
+        self.item_choice.clear();
+        true
+    }
+
+    /// Replaces two components with the item they make, freeing a slot
+    pub fn combine_items(&mut self, first: usize, second: usize) -> bool {
+        if first == second || first.max(second) >= self.items.len() {
+            return false;
+        }
+        let Some(item) = item::recipe(self.items[first], self.items[second]) else {
+            return false;
+        };
+        self.items[first.min(second)] = item;
+        self.items.remove(first.max(second));
+        true
+    }
+
     pub fn branch_value(&self, branch: &CardBranch) -> u32 {
         let mut total = 0;
         for card in &self.cards {
@@ -68,3 +156,26 @@ impl Player {
         total
     }
 }
+
+#[test]
+fn player_round_trip() {
+    use super::{effect::HasEffect, hero};
+
+    let mut player = Player::new(hero::by_id("rasp").unwrap());
+    let mut card = card::by_id("healing_drone").unwrap();
+    card.set_level(3);
+    player.cards.push(card);
+    player.reserve_cards(card::all().into_iter().take(3).collect());
+
+    let json = serde_json::to_string(&player).unwrap();
+    let loaded: Player = serde_json::from_str(&json).unwrap();
+    assert_eq!(format!("{loaded:?}"), format!("{player:?}"));
+
+    let effects: Vec<Box<dyn HasEffect>> = vec![
+        card::effect_by_id("healing_drone", 3).unwrap(),
+        player.hero.abils[0].into(),
+    ];
+    let ron = ron::to_string(&effects).unwrap();
+    let loaded: Vec<Box<dyn HasEffect>> = ron::from_str(&ron).unwrap();
+    assert_eq!(format!("{loaded:?}"), format!("{effects:?}"));
+}
@@ -0,0 +1,144 @@
+use std::{error::Error, fs, path::Path};
+
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    bot::Difficulty,
+    card::{self, CardOps},
+    matchmaking::Matchmaking,
+    player::Player,
+    ruleset::Ruleset,
+    Battle, CardsPool, RoundCapture,
+};
+
+pub const VERSION: u32 = 8;
+
+/// The recorded round's fights together with the whole battle as it was after them,
+/// so the game can go on from the replay exactly as it did
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub round: u32,
+    pub watch: String,
+    pub seed: u64,
+    pub rng: ChaCha12Rng,
+    pub difficulty: Difficulty,
+    pub ruleset: Ruleset,
+    pub matchmaking: Matchmaking,
+    pub players: Vec<Player>,
+    /// players after the round, applied once the fights are watched
+    pub next_players: Vec<Player>,
+    #[serde(deserialize_with = "card::deserialize_pool")]
+    pub pool: Vec<Box<dyn CardOps>>,
+    pub captures: Vec<RoundCapture>,
+}
+
+impl Replay {
+    pub fn new(battle: &Battle, watch: &str, captures: Vec<RoundCapture>) -> Self {
+        Self {
+            version: VERSION,
+            round: battle.round,
+            watch: watch.to_string(),
+            seed: battle.seed,
+            rng: battle.rng.clone(),
+            difficulty: battle.difficulty,
+            ruleset: battle.ruleset.clone(),
+            matchmaking: battle.matchmaking.clone(),
+            players: battle.players.clone(),
+            next_players: battle.next_players.clone(),
+            pool: battle.cards_pool.cards.clone(),
+            captures,
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+        if let Some(dir) = path.as_ref().parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, ron::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let replay: Self = ron::from_str(&fs::read_to_string(path)?)?;
+        if replay.version != VERSION {
+            return Err(format!("unsupported replay version {}", replay.version).into());
+        }
+        Ok(replay)
+    }
+
+    pub fn battle(&self) -> Battle {
+        Battle {
+            players: self.players.clone(),
+            next_players: self.next_players.clone(),
+            cards_pool: CardsPool {
+                players: self.players.len(),
+                cards: self.pool.clone(),
+            },
+            seed: self.seed,
+            rng: self.rng.clone(),
+            round: self.round,
+            difficulty: self.difficulty,
+            ruleset: self.ruleset.clone(),
+            matchmaking: self.matchmaking.clone(),
+        }
+    }
+}
+
+#[test]
+fn replay() {
+    use super::hero;
+
+    let play = |battle: &mut Battle, rounds| {
+        for _ in 0..rounds {
+            for id in battle.players.iter().map(|p| p.hero.id).collect::<Vec<_>>() {
+                battle.ai(id);
+            }
+            battle.round();
+            battle.apply();
+        }
+        format!("{:?}", battle.players)
+    };
+
+    let mut battle = Battle::with_seed(hero::all().into_iter().map(Player::new).collect(), 1);
+    let mut unrecorded = Battle::with_seed(battle.players.clone(), 1);
+    play(&mut battle, 2);
+    play(&mut unrecorded, 2);
+    battle.ai("rasp");
+    unrecorded.ai("rasp");
+    let captures = battle.round();
+    unrecorded.round();
+
+    let path = std::env::temp_dir().join("twg_replay_test.ron");
+    Replay::new(&battle, "rasp", captures.clone())
+        .save(&path)
+        .unwrap();
+    let replay = Replay::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(format!("{:?}", replay.captures), format!("{:?}", captures));
+
+    // the third round is rebuilt as it was, shop, pool and matchmaking included
+    let mut replayed = replay.battle();
+    assert_eq!(replayed.round, 3);
+    assert_eq!(
+        format!("{:?}", replayed.players),
+        format!("{:?}", battle.players)
+    );
+    assert_eq!(
+        format!("{:?}", replayed.cards_pool.cards),
+        format!("{:?}", battle.cards_pool.cards)
+    );
+    assert_eq!(
+        format!("{:?}", replayed.matchmaking),
+        format!("{:?}", battle.matchmaking)
+    );
+
+    // recording doesn't touch the battle and the replay goes on the same way
+    battle.apply();
+    unrecorded.apply();
+    replayed.apply();
+    let played = play(&mut battle, 3);
+    assert_eq!(play(&mut unrecorded, 3), played);
+    assert_eq!(play(&mut replayed, 3), played);
+}
@@ -0,0 +1,164 @@
+use std::{error::Error, fs, path::Path, sync::OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use super::data;
+
+/// Economy and damage rules applied between fights
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ruleset {
+    pub start_money: u32,
+    pub start_hp: i32,
+    /// damage to the loser's hp, grows with every win in a row
+    pub start_attack: u32,
+    pub max_attack: u32,
+    /// paid to both fighters every round
+    pub income: u32,
+    pub win_bonus: u32,
+    /// paid to the loser for every point of the winner's attack
+    pub loss_bonus_per_attack: u32,
+    /// `interest_per_step` for every `interest_step` of money, up to `max_interest`
+    pub interest_step: u32,
+    pub interest_per_step: u32,
+    pub max_interest: u32,
+    pub reroll_cost: u32,
+    /// share of the money spent on a card that selling it returns
+    #[serde(default = "half")]
+    pub sell_refund: f32,
+    #[serde(default = "three")]
+    pub item_slots: u32,
+    /// every that many rounds the players pick one of three items
+    #[serde(default = "three")]
+    pub item_every: u32,
+    /// rounds on which every player fights a creep instead of another player
+    #[serde(default)]
+    pub creep_rounds: Vec<u32>,
+    /// among pairings with as few repeats, prefer players with close hp
+    #[serde(default)]
+    pub matchmaking_by_hp: bool,
+    #[serde(default)]
+    pub odd_player: OddPlayer,
+}
+
+/// Who the player left without a pair fights outside of creep rounds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum OddPlayer {
+    /// copy of another alive player
+    #[default]
+    Ghost,
+    /// the latest creep, as on creep rounds
+    Creep,
+}
+
+fn half() -> f32 {
+    0.5
+}
+
+fn three() -> u32 {
+    3
+}
+
+impl Ruleset {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let data = fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let ruleset: Self =
+            ron::from_str(&data).map_err(|err| format!("{}: {err}", path.display()))?;
+        ruleset
+            .check()
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        Ok(ruleset)
+    }
+
+    /// The ruleset shipped with the game
+    pub fn shipped() -> Result<Self, Box<dyn Error>> {
+        let ruleset: Self = data::load_file("ruleset.ron")?;
+        ruleset.check().map_err(|err| format!("ruleset.ron: {err}"))?;
+        Ok(ruleset)
+    }
+
+    /// Rejects values the economy divides by
+    pub fn check(&self) -> Result<(), Box<dyn Error>> {
+        if self.interest_step == 0 {
+            return Err("interest_step must be above 0".into());
+        }
+        if self.interest_per_step == 0 {
+            return Err("interest_per_step must be above 0".into());
+        }
+        Ok(())
+    }
+
+    pub fn interest(&self, money: u32) -> u32 {
+        (money / self.interest_step * self.interest_per_step).min(self.max_interest)
+    }
+
+    pub fn refund(&self, paid: u32) -> u32 {
+        (paid as f32 * self.sell_refund) as u32
+    }
+
+    /// Money past which the interest stops growing
+    pub fn interest_cap(&self) -> u32 {
+        self.max_interest / self.interest_per_step * self.interest_step
+    }
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        static RULESET: OnceLock<Ruleset> = OnceLock::new();
+        RULESET
+            .get_or_init(|| {
+                Ruleset::shipped()
+                    .unwrap_or_else(|err| panic!("failed to load the ruleset: {err}"))
+            })
+            .clone()
+    }
+}
+
+#[test]
+fn ruleset() {
+    use super::{hero, player::Player, Battle};
+
+    let ruleset = Ruleset {
+        income: 1000,
+        ..Ruleset::default()
+    };
+    assert_eq!(ruleset.interest(2500), 100);
+    assert_eq!(ruleset.interest_cap(), 1000);
+    assert!(ruleset.check().is_ok());
+
+    let path = std::env::temp_dir().join("twg-2024-2-ruleset.ron");
+    let broken = [
+        Ruleset {
+            interest_step: 0,
+            ..ruleset.clone()
+        },
+        Ruleset {
+            interest_per_step: 0,
+            ..ruleset.clone()
+        },
+    ];
+    for broken in broken {
+        fs::write(&path, ron::to_string(&broken).unwrap()).unwrap();
+        assert!(Ruleset::load(&path).is_err());
+    }
+    fs::remove_file(&path).unwrap();
+
+    let players = hero::all()
+        .into_iter()
+        .map(|hero| Player::with_ruleset(hero, &ruleset))
+        .collect();
+    let mut battle = Battle::with_ruleset(players, 1, ruleset.clone());
+    battle.round();
+    battle.apply();
+    assert!(battle
+        .players
+        .iter()
+        .all(|p| p.money >= ruleset.start_money + ruleset.income));
+    assert_eq!(
+        battle
+            .players
+            .iter()
+            .filter(|p| p.hp < ruleset.start_hp)
+            .count(),
+        3
+    );
+}
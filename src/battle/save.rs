@@ -0,0 +1,150 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    bot::Difficulty,
+    card::{self, CardOps},
+    data,
+    matchmaking::Matchmaking,
+    player::Player,
+    ruleset::Ruleset,
+    Battle, CardsPool,
+};
+
+pub const VERSION: u32 = 4;
+
+/// Whole battle between rounds, resumed from the shop phase
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveGame {
+    pub version: u32,
+    /// heroes played by people and whose shop turn it is
+    pub humans: Vec<String>,
+    pub turn: usize,
+    pub round: u32,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub ruleset: Ruleset,
+    #[serde(default)]
+    pub matchmaking: Matchmaking,
+    pub seed: u64,
+    /// exact rng state, so the resumed game draws what the saved one would have
+    pub rng: ChaCha12Rng,
+    pub players: Vec<Player>,
+    #[serde(deserialize_with = "card::deserialize_pool")]
+    pub pool: Vec<Box<dyn CardOps>>,
+}
+
+impl SaveGame {
+    pub fn new(battle: &Battle, humans: &[String], turn: usize) -> Self {
+        Self {
+            version: VERSION,
+            humans: humans.to_vec(),
+            turn,
+            round: battle.round,
+            difficulty: battle.difficulty,
+            ruleset: battle.ruleset.clone(),
+            matchmaking: battle.matchmaking.clone(),
+            seed: battle.seed,
+            rng: battle.rng.clone(),
+            players: battle.players.clone(),
+            pool: battle.cards_pool.cards.clone(),
+        }
+    }
+
+    pub fn path() -> PathBuf {
+        data::user_dir().join("save.ron")
+    }
+
+    pub fn exists() -> bool {
+        Self::path().is_file()
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        fs::create_dir_all(data::user_dir())?;
+        fs::write(Self::path(), ron::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        let save: Self = ron::from_str(&fs::read_to_string(Self::path())?)?;
+        if save.version != VERSION {
+            return Err(format!("unsupported save version {}", save.version).into());
+        }
+        Ok(save)
+    }
+
+    pub fn remove() -> Result<(), Box<dyn Error>> {
+        if Self::exists() {
+            fs::remove_file(Self::path())?;
+        }
+        Ok(())
+    }
+
+    pub fn battle(&self) -> Battle {
+        Battle {
+            players: self.players.clone(),
+            next_players: vec![],
+            cards_pool: CardsPool {
+                players: self.players.len(),
+                cards: self.pool.clone(),
+            },
+            seed: self.seed,
+            rng: self.rng.clone(),
+            round: self.round,
+            difficulty: self.difficulty,
+            ruleset: self.ruleset.clone(),
+            matchmaking: self.matchmaking.clone(),
+        }
+    }
+}
+
+#[test]
+fn save_game() {
+    use super::hero;
+
+    let simulate = |battle: &mut Battle| {
+        let mut log = vec![];
+        for _ in 0..3 {
+            for id in battle.players.iter().map(|p| p.hero.id).collect::<Vec<_>>() {
+                battle.ai(id);
+            }
+            log.push(format!("{:?}", battle.round()));
+            battle.apply();
+        }
+        log
+    };
+
+    let mut battle = Battle::with_seed(hero::all().into_iter().map(Player::new).collect(), 7);
+    simulate(&mut battle);
+    let save = ron::to_string(&SaveGame::new(&battle, &["rasp".to_string()], 0)).unwrap();
+    let mut resumed = ron::from_str::<SaveGame>(&save).unwrap().battle();
+
+    assert_eq!(resumed.round, battle.round);
+    assert_eq!(simulate(&mut resumed), simulate(&mut battle));
+}
+
+#[test]
+fn save_keeps_rng() {
+    use super::hero;
+
+    let play = |save: bool| {
+        let mut battle = Battle::with_seed(hero::all().into_iter().map(Player::new).collect(), 11);
+        let mut captures = vec![];
+        for _ in 0..3 {
+            for id in battle.players.iter().map(|p| p.hero.id).collect::<Vec<_>>() {
+                battle.ai(id);
+                if save {
+                    SaveGame::new(&battle, &[id.to_string()], 0);
+                }
+            }
+            captures.push(format!("{:?}", battle.round()));
+            battle.apply();
+        }
+        (captures, battle.seed())
+    };
+
+    assert_eq!(play(true), play(false));
+}
@@ -0,0 +1,222 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use super::fight::Owner;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum StatusKind {
+    /// No attacks and no mana gain
+    Stun,
+    /// Attack speed reduced by the fraction per stack
+    Slow(f32),
+    /// Damage per second per stack, halves incoming healing
+    Poison(f32),
+    /// Damage per second per stack
+    DamageOverTime(f32),
+    /// Absorbs up to the amount of damage per stack
+    Shield(f32),
+    /// No mana gain
+    Silence,
+    /// Evasion chance added per stack
+    Evasion(f32),
+}
+
+impl StatusKind {
+    pub fn is_buff(&self) -> bool {
+        matches!(self, StatusKind::Shield(_) | StatusKind::Evasion(_))
+    }
+
+    /// Same kind with another amount, kinds without one stay as they are
+    pub fn with_amount(self, amount: f32) -> Self {
+        match self {
+            StatusKind::Slow(_) => StatusKind::Slow(amount),
+            StatusKind::Poison(_) => StatusKind::Poison(amount),
+            StatusKind::DamageOverTime(_) => StatusKind::DamageOverTime(amount),
+            StatusKind::Shield(_) => StatusKind::Shield(amount),
+            StatusKind::Evasion(_) => StatusKind::Evasion(amount),
+            StatusKind::Stun | StatusKind::Silence => self,
+        }
+    }
+
+    fn same(&self, other: &StatusKind) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Dispel {
+    Buffs,
+    Debuffs,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct StatusDesc {
+    pub kind: StatusKind,
+    pub duration: f32,
+    pub max_stacks: u32,
+    pub dispellable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Status {
+    pub kind: StatusKind,
+    pub duration: f32,
+    pub stacks: u32,
+    pub max_stacks: u32,
+    pub dispellable: bool,
+    pub source: Owner,
+    /// id of the ability or card that applied the status
+    pub source_id: Cow<'static, str>,
+    timer: f32,
+}
+
+impl Status {
+    pub fn new(desc: StatusDesc, source: Owner, source_id: Cow<'static, str>) -> Self {
+        Self {
+            kind: desc.kind,
+            duration: desc.duration,
+            stacks: 1,
+            max_stacks: desc.max_stacks.max(1),
+            dispellable: desc.dispellable,
+            source,
+            source_id,
+            timer: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Statuses(Vec<Status>);
+
+impl Statuses {
+    pub fn iter(&self) -> impl Iterator<Item = &Status> {
+        self.0.iter()
+    }
+
+    pub fn has(&self, f: impl Fn(&StatusKind) -> bool) -> bool {
+        self.0.iter().any(|s| f(&s.kind))
+    }
+
+    /// Same kind from the same card or ability stacks up to `max_stacks` and refreshes the duration
+    pub fn apply(&mut self, desc: StatusDesc, source: Owner, source_id: Cow<'static, str>) {
+        let Some(status) = self
+            .0
+            .iter_mut()
+            .find(|s| s.source == source && s.source_id == source_id && s.kind.same(&desc.kind))
+        else {
+            self.0.push(Status::new(desc, source, source_id));
+            return;
+        };
+        status.duration = status.duration.max(desc.duration);
+        match (&mut status.kind, desc.kind) {
+            (StatusKind::Shield(left), StatusKind::Shield(amount)) => {
+                *left = (*left + amount).min(amount * status.max_stacks as f32);
+            }
+            _ => status.kind = desc.kind,
+        }
+        status.stacks = (status.stacks + 1).min(status.max_stacks);
+    }
+
+    pub fn dispel(&mut self, dispel: Dispel) {
+        self.0
+            .retain(|s| !s.dispellable || s.kind.is_buff() != (dispel == Dispel::Buffs));
+    }
+
+    /// Ticks durations, returns the damage over time dealt by each source
+    pub fn update(&mut self, delta: f32) -> Vec<(Owner, Cow<'static, str>, f32)> {
+        let mut damage = vec![];
+        self.0.retain_mut(|status| {
+            status.duration -= delta;
+            status.timer += delta;
+            if status.timer >= 1.0 {
+                status.timer -= 1.0;
+                if let StatusKind::Poison(dps) | StatusKind::DamageOverTime(dps) = status.kind {
+                    damage.push((
+                        status.source,
+                        status.source_id.clone(),
+                        dps * status.stacks as f32,
+                    ));
+                }
+            }
+            status.duration > 0.0
+        });
+        damage
+    }
+
+    pub fn slow(&self) -> f32 {
+        self.0
+            .iter()
+            .map(|s| match s.kind {
+                StatusKind::Slow(slow) => slow * s.stacks as f32,
+                _ => 0.0,
+            })
+            .sum::<f32>()
+            .min(1.0)
+    }
+
+    pub fn evasion(&self) -> f32 {
+        self.0
+            .iter()
+            .map(|s| match s.kind {
+                StatusKind::Evasion(evasion) => evasion * s.stacks as f32,
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Absorbs the damage with shields, returns what got through
+    pub fn absorb(&mut self, mut damage: f32) -> f32 {
+        for status in &mut self.0 {
+            if let StatusKind::Shield(ref mut left) = status.kind {
+                let absorbed = left.min(damage);
+                *left -= absorbed;
+                damage -= absorbed;
+            }
+        }
+        self.0
+            .retain(|s| !matches!(s.kind, StatusKind::Shield(left) if left <= 0.0));
+        damage
+    }
+}
+
+#[test]
+fn statuses() {
+    let poison = StatusDesc {
+        kind: StatusKind::Poison(10.0),
+        duration: 2.5,
+        max_stacks: 2,
+        dispellable: true,
+    };
+    let shield = StatusDesc {
+        kind: StatusKind::Shield(50.0),
+        duration: 10.0,
+        max_stacks: 1,
+        dispellable: true,
+    };
+
+    let mut statuses = Statuses::default();
+    for _ in 0..3 {
+        statuses.apply(poison, Owner::Fighter2, "venom".into());
+    }
+    statuses.apply(shield, Owner::Fighter1, "ward".into());
+    assert_eq!(statuses.iter().count(), 2);
+    assert_eq!(statuses.iter().next().unwrap().stacks, 2);
+
+    let mut damage = 0.0;
+    for _ in 0..300 {
+        damage += statuses.update(0.01).iter().map(|(_, _, d)| d).sum::<f32>();
+    }
+    assert_eq!(damage, 40.0);
+    assert!(!statuses.has(|k| matches!(k, StatusKind::Poison(_))));
+
+    assert_eq!(statuses.absorb(30.0), 0.0);
+    assert_eq!(statuses.absorb(30.0), 10.0);
+    assert_eq!(statuses.iter().count(), 0);
+
+    statuses.apply(poison, Owner::Fighter2, "venom".into());
+    statuses.apply(shield, Owner::Fighter1, "ward".into());
+    statuses.dispel(Dispel::Debuffs);
+    assert!(statuses.has(StatusKind::is_buff));
+    assert_eq!(statuses.iter().count(), 1);
+}
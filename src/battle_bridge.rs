@@ -1,18 +1,27 @@
-use bevy::{ecs::system::EntityCommands, prelude::*};
+use std::{ops::Deref, path::PathBuf};
 
-use crate::battle::{card::CardBranch, hero::Hero, Battle, RoundCapture};
+use bevy::{
+    ecs::system::{EntityCommands, SystemParam},
+    prelude::*,
+};
+
+use crate::{
+    battle::{bot::Difficulty, card::CardBranch, hero::Hero, save::SaveGame, Battle, RoundCapture},
+    net::{client::Client, ClientMessage, ServerMessage},
+};
+
+pub type SpawnHero = Box<dyn for<'a> Fn(&'a mut ChildBuilder) -> EntityCommands<'a> + Send + Sync>;
 
 #[derive(Resource, Deref)]
-pub struct HeroesResource(
-    pub  Vec<(
-        Hero,
-        Box<dyn for<'a> Fn(&'a mut ChildBuilder) -> EntityCommands<'a> + Send + Sync>,
-    )>,
-);
+pub struct HeroesResource(pub Vec<(Hero, SpawnHero)>);
 
 #[derive(Resource, Deref, DerefMut)]
 pub struct BattleResource(pub Battle);
 
+/// Picked on the hero select screen for the next battle
+#[derive(Resource, Default)]
+pub struct DifficultyResource(pub Difficulty);
+
 #[derive(Resource)]
 pub struct RoundCaptureResource(pub Vec<RoundCapture>);
 
@@ -25,6 +34,113 @@ impl RoundCaptureResource {
             RoundCapture::Skip(player) => *player == id,
         })
     }
+
+    /// Fight to show the hero in, creeps fight many players so the watched one goes first
+    pub fn for_hero(&self, id: &str, watch: &str) -> Option<&RoundCapture> {
+        self.by_player(watch)
+            .filter(|c| match c {
+                RoundCapture::Fight {
+                    player1, player2, ..
+                } => *player1 == id || *player2 == id,
+                RoundCapture::Skip(player) => *player == id,
+            })
+            .or_else(|| self.by_player(id))
+    }
+}
+
+#[derive(Resource)]
+pub struct ReplayRecorder(pub PathBuf);
+
+#[derive(Resource)]
+pub struct ReplayPlayback;
+
+#[derive(Resource)]
+pub struct NetClient(pub Client);
+
+impl NetClient {
+    pub fn send(&self, message: ClientMessage) {
+        if let Err(err) = self.0.send(&message) {
+            error!("Failed to send {message:?}: {err}");
+        }
+    }
+}
+
+/// Server messages waiting for the scene that handles them
+#[derive(Resource, Default)]
+pub struct NetInbox {
+    pub shop: Option<SaveGame>,
+    pub round: Option<Vec<RoundCapture>>,
+    pub ended: bool,
+}
+
+pub fn receive(net: Res<NetClient>, mut inbox: ResMut<NetInbox>) {
+    while let Some(message) = net.0.try_recv() {
+        match message {
+            ServerMessage::Shop(save) => inbox.shop = Some(*save),
+            ServerMessage::Round(captures) => inbox.round = Some(captures),
+            ServerMessage::GameEnded => inbox.ended = true,
+            ServerMessage::Error(err) => error!("Server: {err}"),
+        }
+    }
+}
+
+/// Shop actions of the local player, the server applies them when playing online
+#[derive(SystemParam)]
+pub struct Shop<'w> {
+    battle: ResMut<'w, BattleResource>,
+    net: Option<Res<'w, NetClient>>,
+}
+
+impl Deref for Shop<'_> {
+    type Target = Battle;
+
+    fn deref(&self) -> &Battle {
+        &self.battle
+    }
+}
+
+impl Shop<'_> {
+    pub fn buy_card(&mut self, id: &str, index: usize) {
+        match &self.net {
+            Some(net) => net.send(ClientMessage::BuyCard(index)),
+            None => self.battle.buy_card(id, index),
+        }
+    }
+
+    pub fn sell_card(&mut self, id: &str, index: usize) {
+        match &self.net {
+            Some(net) => net.send(ClientMessage::SellCard(index)),
+            None => self.battle.sell_card(id, index),
+        }
+    }
+
+    pub fn pick_item(&mut self, id: &str, index: usize) {
+        match &self.net {
+            Some(net) => net.send(ClientMessage::PickItem(index)),
+            None => self.battle.pick_item(id, index),
+        }
+    }
+
+    pub fn combine_items(&mut self, id: &str, first: usize, second: usize) {
+        match &self.net {
+            Some(net) => net.send(ClientMessage::CombineItems(first, second)),
+            None => self.battle.combine_items(id, first, second),
+        }
+    }
+
+    pub fn reroll(&mut self, id: &str) {
+        match &self.net {
+            Some(net) => net.send(ClientMessage::Reroll),
+            None => self.battle.reroll(id),
+        }
+    }
+
+    pub fn set_cards_locked(&mut self, id: &str, locked: bool) {
+        match &self.net {
+            Some(net) => net.send(ClientMessage::SetCardsLocked(locked)),
+            None => self.battle.set_cards_locked(id, locked),
+        }
+    }
 }
 
 pub fn branch_to_color(branch: &CardBranch) -> Color {
@@ -35,18 +35,22 @@ impl Arena {}
 
 fn init(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    _meshes: ResMut<Assets<Mesh>>,
+    _materials: ResMut<Assets<StandardMaterial>>,
     mut query: Query<(Entity, &mut Arena, &Children), Added<Arena>>,
     asset_server: Res<AssetServer>,
     capture: Res<RoundCaptureResource>,
     hero_ids: Query<&HeroId>,
     with_parent: Query<&Parent>,
 ) {
-    for (entity, mut arena, children) in query.iter_mut() {
+    for (entity, _arena, children) in query.iter_mut() {
+        let mut placed = vec![];
         for capture in &capture.0 {
             for hero in children.iter() {
                 let id = &hero_ids.get(*hero).unwrap().0;
+                if placed.contains(hero) {
+                    continue;
+                }
 
                 let transform = match capture {
                     RoundCapture::Fight {
@@ -108,9 +112,21 @@ fn init(
                         },
                     ))
                     .set_parent(hero_node);
+                placed.push(*hero);
             }
         }
 
+        // creeps nobody fights this round
+        for hero in children.iter().filter(|hero| !placed.contains(hero)) {
+            commands.entity(*hero).insert((
+                TransformBundle::default(),
+                VisibilityBundle {
+                    visibility: Visibility::Hidden,
+                    ..Default::default()
+                },
+            ));
+        }
+
         commands
             .entity(entity)
             .insert((
@@ -137,19 +153,15 @@ fn update(
     time: Res<Time>,
     query: Query<(Entity, &HeroId), With<HeroState>>,
 ) {
-    if round.0.iter().fold(true, |acc, c| {
-        acc && match c {
-            RoundCapture::Fight { fight_capture, .. } => {
-                game_timer.value >= fight_capture.duration()
-            }
-            RoundCapture::Skip(_) => true,
-        }
+    if round.0.iter().all(|c| match c {
+        RoundCapture::Fight { fight_capture, .. } => game_timer.value >= fight_capture.duration(),
+        RoundCapture::Skip(_) => true,
     }) {
         game_timer.fired = true;
     }
 
     for (entity, id) in query.iter() {
-        let round = round.by_player(&id.0).unwrap();
+        let round = round.for_hero(&id.0, &watch.id).unwrap();
 
         let show = match round {
             RoundCapture::Fight {
@@ -1,5 +1,5 @@
 use bevy::{
-    audio::{PlaybackMode, Volume},
+    audio::Volume,
     pbr::{NotShadowCaster, NotShadowReceiver},
     prelude::*,
 };
@@ -9,7 +9,7 @@ use crate::{
     scene::landing::HeroWatch, MASTER_VOLUME,
 };
 
-use super::{fight_state::FightState, LocalSchedule};
+use super::LocalSchedule;
 
 #[derive(Component)]
 pub struct Beam {
@@ -46,11 +46,7 @@ impl Plugin for BeamPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             LocalSchedule,
-            (
-                setup,
-                update,
-                filter.run_if(resource_exists::<HeroWatch>),
-            ),
+            (setup, update, filter.run_if(resource_exists::<HeroWatch>)),
         );
     }
 }
@@ -121,7 +117,7 @@ fn filter(
 ) {
     for (entity, id) in query.iter() {
         let show = match capture {
-            Some(ref capture) => match capture.by_player(&id.0).unwrap() {
+            Some(ref capture) => match capture.for_hero(&id.0, &watch.id).unwrap() {
                 RoundCapture::Fight {
                     player1, player2, ..
                 } => *player1 == watch.id || *player2 == watch.id,
@@ -2,8 +2,6 @@ use std::time::Duration;
 
 use bevy::{animation::RepeatAnimation, prelude::*, utils::hashbrown::HashMap};
 
-use crate::hero::HeroId;
-
 use super::LocalSchedule;
 
 pub struct ComplexAnimPlayerPlugin;
@@ -160,42 +158,38 @@ fn play(
 
         match player.state {
             State::Stop => {}
-            State::Idle => match &player.idle {
-                Some(idle) => {
+            State::Idle => {
+                if let Some(idle) = &player.idle {
                     animations.current = idle.clone();
                     anim_player
                         .play_with_transition(animations.by_name[idle].clone_weak(), TRANSITION)
                         .repeat();
                 }
-                None => {}
-            },
-            State::Attack(speed) => match &player.attack {
-                Some((attack, frames)) => {
+            }
+            State::Attack(speed) => {
+                if let Some((attack, frames)) = &player.attack {
                     let duration = *frames as f32 / 24.0;
                     animations.current = attack.clone();
                     anim_player
                         .play_with_transition(animations.by_name[attack].clone_weak(), TRANSITION)
                         .set_speed(duration * speed);
                 }
-                None => {}
-            },
-            State::Win => match &player.win {
-                Some(win) => {
+            }
+            State::Win => {
+                if let Some(win) = &player.win {
                     animations.current = win.clone();
                     anim_player
                         .play_with_transition(animations.by_name[win].clone_weak(), TRANSITION)
                         .repeat();
                 }
-                None => {}
-            },
-            State::Lose => match &player.lose {
-                Some(lose) => {
+            }
+            State::Lose => {
+                if let Some(lose) = &player.lose {
                     animations.current = lose.clone();
                     anim_player
                         .play_with_transition(animations.by_name[lose].clone_weak(), TRANSITION);
                 }
-                None => {}
-            },
+            }
             State::Showoff(interval) => {
                 if player.state_changed {
                     player.current_showoff = None;
@@ -209,17 +203,14 @@ fn play(
                             player.current_showoff = Some(player.showoffs[0].clone());
                             player.current_showoff.as_mut().unwrap()
                         } else {
-                            match &player.idle {
-                                Some(idle) => {
-                                    animations.current = idle.clone();
-                                    anim_player
-                                        .play_with_transition(
-                                            animations.by_name[idle].clone_weak(),
-                                            TRANSITION,
-                                        )
-                                        .repeat();
-                                }
-                                None => {}
+                            if let Some(idle) = &player.idle {
+                                animations.current = idle.clone();
+                                anim_player
+                                    .play_with_transition(
+                                        animations.by_name[idle].clone_weak(),
+                                        TRANSITION,
+                                    )
+                                    .repeat();
                             }
                             player.timer += time.delta_seconds();
                             continue;
@@ -16,10 +16,7 @@ impl Plugin for HomePlugin {
 }
 
 #[derive(Component)]
-pub struct HeroState {
-    pub active: bool,
-    pub changed: bool,
-}
+pub struct HeroState;
 
 #[derive(Component)]
 pub struct Home {}
@@ -28,12 +25,12 @@ impl Home {}
 
 fn init(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    _meshes: ResMut<Assets<Mesh>>,
+    _materials: ResMut<Assets<StandardMaterial>>,
     mut query: Query<(Entity, &mut Home, &Children), Added<Home>>,
     asset_server: Res<AssetServer>,
 ) {
-    for (entity, mut home, children) in query.iter_mut() {
+    for (entity, _home, children) in query.iter_mut() {
         for hero in children.iter() {
             let transform = TransformBundle {
                 local: Transform::from_translation(Vec3::new(0.0, 0.0, 0.0)),
@@ -42,10 +39,7 @@ fn init(
 
             commands.entity(*hero).insert((
                 transform,
-                HeroState {
-                    active: true,
-                    changed: true,
-                },
+                HeroState,
                 VisibilityBundle {
                     visibility: Visibility::Hidden,
                     ..Default::default()
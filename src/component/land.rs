@@ -51,7 +51,7 @@ fn added(
     hero_ids: Query<&HeroId>,
 ) {
     for (entity, children) in query.iter_mut() {
-        let mut children = children.iter().map(|e| *e).collect::<Vec<_>>();
+        let mut children = children.iter().copied().collect::<Vec<_>>();
         children.sort_unstable_by_key(|c| hero_ids.get(*c).unwrap().0 != selected.id);
 
         for (i, child) in children.into_iter().enumerate() {
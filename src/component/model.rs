@@ -12,7 +12,7 @@ impl<T> Model<T> {
     pub fn new(gltf: Handle<Gltf>) -> Self {
         Self {
             handle: gltf,
-            _pd: PhantomData::default(),
+            _pd: PhantomData,
         }
     }
 }
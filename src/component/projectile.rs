@@ -103,7 +103,7 @@ fn init(
             .uniform(writer.lit(Vec3::NEG_ONE))
             .cross(writer.prop(normal))
             .normalized();
-        let velocity = (writer.prop(normal) + tangent * writer.lit(0.0).uniform(writer.lit(0.5)))
+        let _velocity = (writer.prop(normal) + tangent * writer.lit(0.0).uniform(writer.lit(0.5)))
             * writer.lit(1.0).uniform(writer.lit(2.0));
 
         let init_vel =
@@ -184,7 +184,7 @@ fn update(
         mut transform,
     ) in projectiles.iter_mut()
     {
-        let round = capture.by_player(&id.0).unwrap();
+        let round = capture.for_hero(&id.0, &watch.id).unwrap();
 
         let show = match round {
             RoundCapture::Fight {
@@ -1,15 +1,12 @@
-use std::{
-    f32::consts::{FRAC_PI_2, FRAC_PI_3, TAU},
-    time::Duration,
-};
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_3, TAU};
 
-use bevy::{audio::{PlaybackMode, Volume}, gltf::Gltf, prelude::*, render::view::visibility};
+use bevy::{audio::Volume, gltf::Gltf, prelude::*};
 
 use crate::{
     battle::modifier::Modifier,
     component::{
         arena,
-        complex_anim_player::{self, Animations, ComplexAnimPart, ComplexAnimPlayer, Showoff},
+        complex_anim_player::{self, Animations, ComplexAnimPlayer},
         fight_state::FightState,
         model::Model,
         projectile::{Projectile, ProjectileConfig},
@@ -17,7 +14,8 @@ use crate::{
     scene::{
         avatars::{self, AvatarLocation},
         Root,
-    }, MASTER_VOLUME,
+    },
+    MASTER_VOLUME,
 };
 
 use super::{HeroId, LocalSchedule};
@@ -72,15 +70,15 @@ fn on_add(
         return;
     }
 
+    let model_def = super::model_def("dimas");
+
     let gltf = match model {
         Some(model) => match assets_gltf.get(&model.handle) {
             Some(gltf) => gltf,
             None => return,
         },
         None => {
-            commands.insert_resource(Model::<Dimas>::new(
-                asset_server.load("embedded://dimas.glb"),
-            ));
+            commands.insert_resource(Model::<Dimas>::new(asset_server.load(&model_def.gltf)));
             return;
         }
     };
@@ -93,17 +91,12 @@ fn on_add(
                 State {
                     swiborg: asset_server.load("embedded://swiborg.glb#Scene0"),
                 },
-                ProjectileConfig {
-                    transform: Transform::from_translation(Vec3::new(1.03829, 1.91548, 0.0)),
-                    color: Color::ORANGE,
-                    radius: 0.3,
-                    ..Default::default()
-                },
+                super::projectile_config(model_def),
             ))
             .with_children(|p| {
                 p.spawn(SceneBundle {
                     scene: gltf.scenes[0].clone(),
-                    transform: Transform::from_scale(Vec3::splat(1.0)),
+                    transform: Transform::from_scale(Vec3::splat(model_def.scale)),
                     ..Default::default()
                 });
                 p.spawn((
@@ -121,17 +114,7 @@ fn on_add(
             for parent in children.iter_ancestors(anim_player) {
                 if parent == entity.id() {
                     entity.insert((
-                        ComplexAnimPlayer::new(anim_player)
-                            .with_idle("idle_track")
-                            .with_attack("attack_track", 40)
-                            .with_win("win_track")
-                            .with_lose("lose_track")
-                            .with_showoff(Showoff::new(vec![ComplexAnimPart {
-                                name: "legs_sit_track".to_string(),
-                                repeat: 1,
-                                speed: 2.0,
-                                wait: Duration::from_millis(0),
-                            }])),
+                        super::anim_player(model_def, anim_player),
                         Animations::new(gltf.named_animations.clone()),
                     ));
                 }
@@ -141,14 +124,15 @@ fn on_add(
 }
 
 fn filter_animations(
-    mut query: Query<&Animations, With<Dimas>>,
-    mut named: Query<(&Name, &mut Visibility)>,
+    _query: Query<&Animations, With<Dimas>>,
+    _named: Query<(&Name, &mut Visibility)>,
 ) {
 }
 
 fn on_avatar(mut query: Query<(&mut ComplexAnimPlayer, &mut avatars::HeroState), With<Dimas>>) {
     for (mut anim_player, mut state) in query.iter_mut() {
-        let origin = Vec3::new(0.6, 2.2, 1.8);
+        let model_def = super::model_def("dimas");
+        let origin = Vec3::from(model_def.camera);
         let target = origin.xy().extend(0.0);
         state.camera = match state.location {
             AvatarLocation::Thumbnail => {
@@ -195,19 +179,17 @@ fn on_arena(
 
         for modifier in &arena_state.modifiers {
             match modifier {
-                Modifier::NormalAttack => {
-                    if visibility.get() {
-                        commands.entity(entity).with_children(|p| {
-                            p.spawn(AudioBundle {
-                                source: asset_server.load("embedded://shoot1.ogg"),
-                                settings: PlaybackSettings {
-                                    // mode: PlaybackMode::Despawn,
-                                    volume: Volume::new(MASTER_VOLUME),
-                                    ..Default::default()
-                                },
-                            });
+                Modifier::NormalAttack if visibility.get() => {
+                    commands.entity(entity).with_children(|p| {
+                        p.spawn(AudioBundle {
+                            source: asset_server.load(&super::model_def("dimas").shoot_sound),
+                            settings: PlaybackSettings {
+                                // mode: PlaybackMode::Despawn,
+                                volume: Volume::new(MASTER_VOLUME),
+                                ..Default::default()
+                            },
                         });
-                    }
+                    });
                 }
                 Modifier::SpawnSwiborg(i) => {
                     commands.entity(ring).with_children(|p| {
@@ -282,7 +264,7 @@ fn swiborg_ring(
     query: Query<(Entity, &SwiborgRing, &Children)>,
     time: Res<Time>,
 ) {
-    for (entity, ring, children) in query.iter() {
+    for (entity, _ring, children) in query.iter() {
         let step = TAU / children.len() as f32;
         for (i, child) in children.iter().enumerate() {
             let ang = i as f32 * step;
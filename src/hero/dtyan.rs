@@ -1,21 +1,21 @@
-use std::{f32::consts::FRAC_PI_6, time::Duration};
+use std::f32::consts::FRAC_PI_6;
 
-use bevy::{audio::{PlaybackMode, Volume}, gltf::Gltf, prelude::*};
+use bevy::{audio::Volume, gltf::Gltf, prelude::*};
 
 use crate::{
     battle::modifier::Modifier,
     component::{
         arena,
         beam::Beam,
-        complex_anim_player::{self, Animations, ComplexAnimPart, ComplexAnimPlayer, Showoff},
+        complex_anim_player::{self, Animations, ComplexAnimPlayer},
         fight_state::FightState,
         model::Model,
-        projectile::ProjectileConfig,
     },
     scene::{
         avatars::{self, AvatarLocation},
         Root,
-    }, MASTER_VOLUME,
+    },
+    MASTER_VOLUME,
 };
 
 use super::{HeroId, LocalSchedule};
@@ -23,9 +23,6 @@ use super::{HeroId, LocalSchedule};
 #[derive(Component)]
 pub struct DTyan;
 
-#[derive(Component)]
-struct State;
-
 #[derive(Component)]
 struct Ready;
 
@@ -61,15 +58,15 @@ fn on_add(
         return;
     }
 
+    let model_def = super::model_def("dtyan");
+
     let gltf = match model {
         Some(model) => match assets_gltf.get(&model.handle) {
             Some(gltf) => gltf,
             None => return,
         },
         None => {
-            commands.insert_resource(Model::<DTyan>::new(
-                asset_server.load("embedded://derevotyan.glb"),
-            ));
+            commands.insert_resource(Model::<DTyan>::new(asset_server.load(&model_def.gltf)));
             return;
         }
     };
@@ -81,7 +78,7 @@ fn on_add(
             .with_children(|p| {
                 p.spawn(SceneBundle {
                     scene: gltf.scenes[0].clone(),
-                    transform: Transform::from_scale(Vec3::splat(0.1)),
+                    transform: Transform::from_scale(Vec3::splat(model_def.scale)),
                     ..Default::default()
                 });
             });
@@ -94,40 +91,9 @@ fn on_add(
             for parent in children.iter_ancestors(anim_player) {
                 if parent == entity.id() {
                     entity.insert((
-                        ComplexAnimPlayer::new(anim_player)
-                            .with_idle("idle_track")
-                            .with_attack("attack_track", 40)
-                            .with_win("win_track")
-                            .with_lose("lose_track")
-                            .with_showoff(Showoff::new(vec![
-                                ComplexAnimPart {
-                                    name: "hand_to_glasses_track".to_string(),
-                                    repeat: 1,
-                                    speed: 1.0,
-                                    wait: Duration::from_millis(500),
-                                },
-                                ComplexAnimPart {
-                                    name: "glasses_off_track".to_string(),
-                                    repeat: 1,
-                                    speed: 1.0,
-                                    wait: Duration::from_millis(1000),
-                                },
-                                ComplexAnimPart {
-                                    name: "glasses_on_track".to_string(),
-                                    repeat: 1,
-                                    speed: 1.0,
-                                    wait: Duration::from_millis(0),
-                                },
-                            ])),
+                        super::anim_player(model_def, anim_player),
                         Animations::new(gltf.named_animations.clone()),
-                        ProjectileConfig {
-                            transform: Transform::from_translation(Vec3::new(
-                                0.140844, 1.71569, 1.30712,
-                            )),
-                            color: Color::GREEN,
-                            radius: 0.2,
-                            ..Default::default()
-                        },
+                        super::projectile_config(model_def),
                     ));
                 }
             }
@@ -164,7 +130,8 @@ fn filter_animations(
 
 fn on_avatar(mut query: Query<(&mut ComplexAnimPlayer, &mut avatars::HeroState), With<DTyan>>) {
     for (mut anim_player, mut state) in query.iter_mut() {
-        let origin = Vec3::new(0.0, 2.3, 0.75);
+        let model_def = super::model_def("dtyan");
+        let origin = Vec3::from(model_def.camera);
         let target = origin.xy().extend(0.0);
         state.camera = match state.location {
             AvatarLocation::Thumbnail => {
@@ -198,19 +165,17 @@ fn on_arena(
     for (entity, arena_state, id, visibility) in query.iter() {
         for modifier in &arena_state.modifiers {
             match modifier {
-                Modifier::NormalAttack => {
-                    if visibility.get() {
-                        commands.entity(entity).with_children(|p| {
-                            p.spawn(AudioBundle {
-                                source: asset_server.load("embedded://shoot2.ogg"),
-                                settings: PlaybackSettings {
-                                    // mode: PlaybackMode::Despawn,
-                                    volume: Volume::new(MASTER_VOLUME),
-                                    ..Default::default()
-                                },
-                            });
+                Modifier::NormalAttack if visibility.get() => {
+                    commands.entity(entity).with_children(|p| {
+                        p.spawn(AudioBundle {
+                            source: asset_server.load(&super::model_def("dtyan").shoot_sound),
+                            settings: PlaybackSettings {
+                                // mode: PlaybackMode::Despawn,
+                                volume: Volume::new(MASTER_VOLUME),
+                                ..Default::default()
+                            },
                         });
-                    }
+                    });
                 }
                 Modifier::ShootHealBeam => {
                     let offset = transforms.get(entity).unwrap().translation();
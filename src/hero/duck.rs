@@ -1,19 +1,12 @@
-use std::{
-    f32::consts::{FRAC_PI_2, FRAC_PI_6, PI},
-    time::Duration,
-};
+use std::f32::consts::{FRAC_PI_6, PI};
 
-use bevy::{
-    audio::{PlaybackMode, Volume},
-    gltf::Gltf,
-    prelude::*,
-};
+use bevy::{audio::Volume, gltf::Gltf, prelude::*};
 
 use crate::{
     battle::modifier::Modifier,
     component::{
         arena,
-        complex_anim_player::{self, Animations, ComplexAnimPart, ComplexAnimPlayer, Showoff},
+        complex_anim_player::{self, Animations, ComplexAnimPlayer},
         fight_state::FightState,
         model::Model,
         projectile::{Projectile, ProjectileConfig},
@@ -70,13 +63,15 @@ fn on_add(
         return;
     }
 
+    let model_def = super::model_def("duck");
+
     let gltf = match model {
         Some(model) => match assets_gltf.get(&model.handle) {
             Some(gltf) => gltf,
             None => return,
         },
         None => {
-            commands.insert_resource(Model::<Duck>::new(asset_server.load("embedded://duck.glb")));
+            commands.insert_resource(Model::<Duck>::new(asset_server.load(&model_def.gltf)));
             return;
         }
     };
@@ -89,17 +84,12 @@ fn on_add(
                 State {
                     duck: asset_server.load("embedded://duck.glb#Scene0"),
                 },
-                ProjectileConfig {
-                    transform: Transform::from_translation(Vec3::new(0.0, 1.086545, 0.97346)),
-                    color: Color::YELLOW,
-                    radius: 0.1,
-                    ..Default::default()
-                },
+                super::projectile_config(model_def),
             ))
             .with_children(|p| {
                 p.spawn(SceneBundle {
                     scene: gltf.scenes[0].clone(),
-                    transform: Transform::from_scale(Vec3::splat(0.5)),
+                    transform: Transform::from_scale(Vec3::splat(model_def.scale)),
                     ..Default::default()
                 });
             });
@@ -112,17 +102,7 @@ fn on_add(
             for parent in children.iter_ancestors(anim_player) {
                 if parent == entity.id() {
                     entity.insert((
-                        ComplexAnimPlayer::new(anim_player)
-                            .with_idle("idle_track")
-                            .with_attack("attack_track", 40)
-                            .with_win("win_track")
-                            .with_lose("lose_track")
-                            .with_showoff(Showoff::new(vec![ComplexAnimPart {
-                                name: "flapping_track".to_string(),
-                                repeat: 1,
-                                speed: 2.0,
-                                wait: Duration::from_millis(0),
-                            }])),
+                        super::anim_player(model_def, anim_player),
                         Animations::new(gltf.named_animations.clone()),
                     ));
                 }
@@ -132,22 +112,22 @@ fn on_add(
 }
 
 fn filter_animations(
-    mut query: Query<&Animations, With<Duck>>,
-    mut named: Query<(&Name, &mut Visibility)>,
+    _query: Query<&Animations, With<Duck>>,
+    _named: Query<(&Name, &mut Visibility)>,
 ) {
 }
 
 fn on_avatar(mut query: Query<(&mut ComplexAnimPlayer, &mut avatars::HeroState), With<Duck>>) {
     for (mut anim_player, mut state) in query.iter_mut() {
-        let origin = Vec3::new(0.0, 1.2, 1.5);
+        let model_def = super::model_def("duck");
+        let origin = Vec3::from(model_def.camera);
         let target = origin.xy().extend(0.0);
         state.camera = match state.location {
             AvatarLocation::Thumbnail => {
                 Transform::from_translation(origin).looking_at(target, Vec3::Y)
             }
             _ => {
-                let origin = Vec3::new(0.0, 1.2, 2.5);
-                let target = Vec3::new(0.0, 0.6, 0.0);
+                let (origin, target) = super::close_up(model_def).unwrap_or((origin, target));
                 anim_player.play(false, complex_anim_player::State::Idle);
                 Transform::from_translation(
                     target + Quat::from_rotation_y(-FRAC_PI_6) * (origin - target),
@@ -181,19 +161,17 @@ fn on_arena(
     for (entity, arena_state, state, id, visibility) in query.iter() {
         for modifier in &arena_state.modifiers {
             match modifier {
-                Modifier::NormalAttack => {
-                    if visibility.get() {
-                        commands.entity(entity).with_children(|p| {
-                            p.spawn(AudioBundle {
-                                source: asset_server.load("embedded://shoot3.ogg"),
-                                settings: PlaybackSettings {
-                                    // mode: PlaybackMode::Despawn,
-                                    volume: Volume::new(MASTER_VOLUME),
-                                    ..Default::default()
-                                },
-                            });
+                Modifier::NormalAttack if visibility.get() => {
+                    commands.entity(entity).with_children(|p| {
+                        p.spawn(AudioBundle {
+                            source: asset_server.load(&super::model_def("duck").shoot_sound),
+                            settings: PlaybackSettings {
+                                // mode: PlaybackMode::Despawn,
+                                volume: Volume::new(MASTER_VOLUME),
+                                ..Default::default()
+                            },
                         });
-                    }
+                    });
                 }
                 Modifier::ShootDuck => {
                     if visibility.get() {
@@ -0,0 +1,178 @@
+use std::f32::consts::FRAC_PI_6;
+
+use bevy::{audio::Volume, gltf::Gltf, prelude::*};
+
+use crate::{
+    battle::modifier::Modifier,
+    component::{
+        arena,
+        beam::Beam,
+        complex_anim_player::{self, Animations, ComplexAnimPlayer},
+        fight_state::FightState,
+    },
+    scene::{
+        avatars::{self, AvatarLocation},
+        Root,
+    },
+    MASTER_VOLUME,
+};
+
+use super::{HeroId, LocalSchedule};
+
+// heroes defined only in assets/heroes, without a plugin of their own
+#[derive(Component)]
+pub struct Generic;
+
+#[derive(Component)]
+pub struct Ready;
+
+#[derive(Component)]
+struct ModelHandle(Handle<Gltf>);
+
+#[derive(Component)]
+pub struct ModelReady;
+
+impl Plugin for Generic {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            LocalSchedule,
+            (
+                on_add,
+                on_avatar,
+                on_arena.run_if(resource_exists::<FightState>),
+            ),
+        );
+    }
+}
+
+fn on_add(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    assets_gltf: Res<Assets<Gltf>>,
+    query: Query<(Entity, &HeroId), (With<Generic>, Without<ModelHandle>)>,
+    query_model: Query<(Entity, &HeroId, &ModelHandle), (With<Generic>, Without<ModelReady>)>,
+    query_animation: Query<
+        (Entity, &HeroId, &ModelHandle),
+        (With<Generic>, With<ModelReady>, Without<Ready>),
+    >,
+    children: Query<&Parent>,
+    anim_players: Query<Entity, With<AnimationPlayer>>,
+) {
+    for (entity, id) in query.iter() {
+        let model_def = super::model_def(id);
+        commands
+            .entity(entity)
+            .insert(ModelHandle(asset_server.load(&model_def.gltf)));
+    }
+
+    for (entity, id, handle) in query_model.iter() {
+        let Some(gltf) = assets_gltf.get(&handle.0) else {
+            continue;
+        };
+        let model_def = super::model_def(id);
+        commands
+            .entity(entity)
+            .insert((ModelReady, super::projectile_config(model_def)))
+            .with_children(|p| {
+                p.spawn(SceneBundle {
+                    scene: gltf.scenes[0].clone(),
+                    transform: Transform::from_scale(Vec3::splat(model_def.scale)),
+                    ..Default::default()
+                });
+            });
+    }
+
+    for (entity, id, handle) in query_animation.iter() {
+        let Some(gltf) = assets_gltf.get(&handle.0) else {
+            continue;
+        };
+        for anim_player in anim_players.iter() {
+            if children.iter_ancestors(anim_player).any(|p| p == entity) {
+                commands.entity(entity).insert((
+                    Ready,
+                    super::anim_player(super::model_def(id), anim_player),
+                    Animations::new(gltf.named_animations.clone()),
+                ));
+            }
+        }
+    }
+}
+
+fn on_avatar(
+    mut query: Query<(&mut ComplexAnimPlayer, &mut avatars::HeroState, &HeroId), With<Generic>>,
+) {
+    for (mut anim_player, mut state, id) in query.iter_mut() {
+        let model_def = super::model_def(id);
+        let origin = Vec3::from(model_def.camera);
+        let target = origin.xy().extend(0.0);
+        state.camera = match state.location {
+            AvatarLocation::Thumbnail => {
+                Transform::from_translation(origin).looking_at(target, Vec3::Y)
+            }
+            _ => {
+                let (origin, target) = super::close_up(model_def).unwrap_or((origin, target));
+                anim_player.play(false, complex_anim_player::State::Idle);
+                Transform::from_translation(
+                    target + Quat::from_rotation_y(-FRAC_PI_6) * (origin - target),
+                )
+                .looking_at(target, Vec3::Y)
+            }
+        }
+    }
+}
+
+fn on_arena(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    query: Query<
+        (Entity, &arena::HeroState, &HeroId, &InheritedVisibility),
+        (With<Generic>, With<Ready>),
+    >,
+    transforms: Query<&GlobalTransform>,
+    root: Query<Entity, With<Root>>,
+) {
+    let Ok(root) = root.get_single() else {
+        return;
+    };
+
+    for (entity, arena_state, id, visibility) in query.iter() {
+        for modifier in &arena_state.modifiers {
+            match modifier {
+                Modifier::NormalAttack if visibility.get() => {
+                    commands.entity(entity).with_children(|p| {
+                        p.spawn(AudioBundle {
+                            source: asset_server.load(&super::model_def(id).shoot_sound),
+                            settings: PlaybackSettings {
+                                volume: Volume::new(MASTER_VOLUME),
+                                ..Default::default()
+                            },
+                        });
+                    });
+                }
+                Modifier::ShootHealBeam => {
+                    let offset = transforms.get(entity).unwrap().translation();
+                    commands.entity(root).with_children(|p| {
+                        p.spawn((
+                            id.clone(),
+                            Beam::new(1.0)
+                                .with_transform(Transform::from_translation(offset))
+                                .with_color(Color::LIME_GREEN * 2.0),
+                        ));
+                    });
+                }
+                Modifier::ShootDamageBeam => {
+                    let offset = transforms.get(arena_state.enemy).unwrap().translation();
+                    commands.entity(root).with_children(|p| {
+                        p.spawn((
+                            id.clone(),
+                            Beam::new(1.0)
+                                .with_transform(Transform::from_translation(offset))
+                                .with_color(Color::RED * 2.0),
+                        ));
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+}
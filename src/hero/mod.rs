@@ -1,20 +1,32 @@
+use std::time::Duration;
+
 use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
 use dimas::Dimas;
 use dtyan::DTyan;
 use duck::Duck;
+use generic::Generic;
 use kisanya::Kisanya;
 use nulch::Nulch;
 use rasp::Rasp;
 
 use crate::{
     battle::{
+        creep,
         fight::Owner,
-        hero::{dimas, dtyan, duck, kisanya, nulch, rasp},
+        ghost,
+        hero::{self, Hero, ModelDef, Spawner},
         modifier::Modifier,
     },
-    battle_bridge::HeroesResource,
+    battle_bridge::{HeroesResource, SpawnHero},
     component::{
-        arena, complex_anim_player::{self, ComplexAnimPlayer, SHOWOFF_IMMEDIATE, SHOWOFF_LAZY}, fight_state::FightState, home, land, projectile::{Projectile, ProjectileConfig}, wheel
+        arena,
+        complex_anim_player::{
+            self, ComplexAnimPart, ComplexAnimPlayer, Showoff, SHOWOFF_IMMEDIATE, SHOWOFF_LAZY,
+        },
+        fight_state::FightState,
+        home, land,
+        projectile::{Projectile, ProjectileConfig},
+        wheel,
     },
     scene::Root,
 };
@@ -25,6 +37,10 @@ pub struct LocalSchedule;
 #[derive(Component)]
 pub struct HeroesRoot;
 
+/// Heroes root that also gets the creeps and ghosts, they only show up in fights
+#[derive(Component)]
+pub struct WithOpponents;
+
 #[derive(Component, Deref, Clone)]
 pub struct HeroId(pub String);
 
@@ -32,7 +48,7 @@ pub struct HeroesPlugin;
 
 impl Plugin for HeroesPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((Nulch, Rasp, DTyan, Dimas, Duck, Kisanya));
+        app.add_plugins((Nulch, Rasp, DTyan, Dimas, Duck, Kisanya, Generic));
         app.add_systems(
             LocalSchedule,
             (
@@ -43,27 +59,90 @@ impl Plugin for HeroesPlugin {
                 (on_arena_animations, on_arena_projectiles).run_if(resource_exists::<FightState>),
             ),
         );
-        app.insert_resource(HeroesResource(vec![
-            (nulch(), Box::new(|cmd| cmd.spawn(Nulch))),
-            (rasp(), Box::new(|cmd| cmd.spawn(Rasp))),
-            (dtyan(), Box::new(|cmd| cmd.spawn(DTyan))),
-            (dimas(), Box::new(|cmd| cmd.spawn(Dimas))),
-            (duck(), Box::new(|cmd| cmd.spawn(Duck))),
-            (kisanya(), Box::new(|cmd| cmd.spawn(Kisanya))),
-        ]));
+        app.insert_resource(HeroesResource(
+            hero::defs()
+                .iter()
+                .map(|def| {
+                    let spawn: SpawnHero = match def.model.spawner {
+                        Spawner::Generic => Box::new(|cmd| cmd.spawn(Generic)),
+                        Spawner::Nulch => Box::new(|cmd| cmd.spawn(Nulch)),
+                        Spawner::Rasp => Box::new(|cmd| cmd.spawn(Rasp)),
+                        Spawner::DTyan => Box::new(|cmd| cmd.spawn(DTyan)),
+                        Spawner::Dimas => Box::new(|cmd| cmd.spawn(Dimas)),
+                        Spawner::Duck => Box::new(|cmd| cmd.spawn(Duck)),
+                        Spawner::Kisanya => Box::new(|cmd| cmd.spawn(Kisanya)),
+                    };
+                    (Hero::new(def), spawn)
+                })
+                .collect(),
+        ));
+    }
+}
+
+pub fn model_def(id: &str) -> &'static ModelDef {
+    let id = creep::def(id).map_or(id, |creep| &creep.model);
+    let id = ghost::def(id).map_or(id, |ghost| &ghost.hero.id);
+    &hero::def(id)
+        .unwrap_or_else(|| panic!("unknown hero {id}"))
+        .model
+}
+
+pub fn anim_player(model_def: &ModelDef, anim_player: Entity) -> ComplexAnimPlayer {
+    let anims = &model_def.animations;
+    ComplexAnimPlayer::new(anim_player)
+        .with_idle(&anims.idle)
+        .with_attack(&anims.attack, anims.attack_frames)
+        .with_win(&anims.win)
+        .with_lose(&anims.lose)
+        .with_showoff(Showoff::new(
+            anims
+                .showoff
+                .iter()
+                .map(|part| ComplexAnimPart {
+                    name: part.track.clone(),
+                    repeat: part.repeat,
+                    speed: part.speed,
+                    wait: Duration::from_millis(part.wait_ms),
+                })
+                .collect(),
+        ))
+}
+
+/// Camera position and target on the hero's page, if the model sets its own
+pub fn close_up(model_def: &ModelDef) -> Option<(Vec3, Vec3)> {
+    let close_up = model_def.close_up.as_ref()?;
+    Some((Vec3::from(close_up.origin), Vec3::from(close_up.target)))
+}
+
+pub fn projectile_config(model_def: &ModelDef) -> ProjectileConfig {
+    let (x, y, z) = model_def.projectile.offset;
+    let (r, g, b) = model_def.projectile.color;
+    ProjectileConfig {
+        transform: Transform::from_xyz(x, y, z),
+        color: Color::rgb(r, g, b),
+        radius: model_def.projectile.radius,
+        ..Default::default()
     }
 }
 
 fn init_heroes(
     mut commands: Commands,
-    root: Query<Entity, Added<HeroesRoot>>,
+    root: Query<(Entity, Has<WithOpponents>), Added<HeroesRoot>>,
     heroes: Res<HeroesResource>,
 ) {
-    for root in root.iter() {
+    for (root, with_opponents) in root.iter() {
         commands.entity(root).with_children(|p| {
             heroes.iter().for_each(|(hero, spawn)| {
                 spawn(p).insert(HeroId(hero.id.to_string()));
-            })
+            });
+            if with_opponents {
+                for (hero, spawn) in heroes.iter() {
+                    spawn(p).insert(HeroId(ghost::of_hero(hero.id).id.clone()));
+                }
+                for creep in creep::defs() {
+                    p.spawn((Generic, HeroId(creep.id.clone())));
+                }
+            }
         });
     }
 }
@@ -138,18 +217,15 @@ fn on_arena_projectiles(
 
     for (entity, mut anim_player, id, projectile_config, hero_state) in query.iter_mut() {
         for modifier in &hero_state.modifiers {
-            match modifier {
-                Modifier::NormalAttack => {
-                    anim_player.replay();
-                    commands.entity(root).with_children(|p| {
-                        p.spawn((
-                            Projectile::new(entity, Some(hero_state.enemy), 0.5),
-                            id.clone(),
-                            projectile_config.clone(),
-                        ));
-                    });
-                }
-                _ => {}
+            if let Modifier::NormalAttack = modifier {
+                anim_player.replay();
+                commands.entity(root).with_children(|p| {
+                    p.spawn((
+                        Projectile::new(entity, Some(hero_state.enemy), 0.5),
+                        id.clone(),
+                        projectile_config.clone(),
+                    ));
+                });
             }
         }
     }
@@ -158,6 +234,7 @@ fn on_arena_projectiles(
 pub mod dimas;
 pub mod dtyan;
 pub mod duck;
+pub mod generic;
 pub mod kisanya;
 pub mod nulch;
 pub mod rasp;
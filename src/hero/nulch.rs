@@ -1,21 +1,21 @@
-use std::{f32::consts::FRAC_PI_6, time::Duration};
+use std::f32::consts::FRAC_PI_6;
 
-use bevy::{audio::{PlaybackMode, Volume}, gltf::Gltf, prelude::*, render::view::visibility};
+use bevy::{audio::Volume, gltf::Gltf, prelude::*};
 
 use crate::{
     battle::modifier::Modifier,
     component::{
         arena,
         beam::Beam,
-        complex_anim_player::{self, Animations, ComplexAnimPart, ComplexAnimPlayer, Showoff},
+        complex_anim_player::{self, Animations, ComplexAnimPlayer},
         fight_state::FightState,
         model::Model,
-        projectile::ProjectileConfig,
     },
     scene::{
         avatars::{self, AvatarLocation},
         Root,
-    }, MASTER_VOLUME,
+    },
+    MASTER_VOLUME,
 };
 
 use super::{HeroId, LocalSchedule};
@@ -58,15 +58,15 @@ fn on_add(
         return;
     }
 
+    let model_def = super::model_def("nulch");
+
     let gltf = match model {
         Some(model) => match assets_gltf.get(&model.handle) {
             Some(gltf) => gltf,
             None => return,
         },
         None => {
-            commands.insert_resource(Model::<Nulch>::new(
-                asset_server.load("embedded://nulch.glb"),
-            ));
+            commands.insert_resource(Model::<Nulch>::new(asset_server.load(&model_def.gltf)));
             return;
         }
     };
@@ -78,7 +78,7 @@ fn on_add(
             .with_children(|p| {
                 p.spawn(SceneBundle {
                     scene: gltf.scenes[0].clone(),
-                    transform: Transform::from_scale(Vec3::splat(0.1)),
+                    transform: Transform::from_scale(Vec3::splat(model_def.scale)),
                     ..Default::default()
                 });
             });
@@ -91,26 +91,9 @@ fn on_add(
             for parent in children.iter_ancestors(anim_player) {
                 if parent == entity.id() {
                     entity.insert((
-                        ComplexAnimPlayer::new(anim_player)
-                            .with_idle("idle_track")
-                            .with_attack("attack_track", 40)
-                            .with_win("win_track")
-                            .with_lose("lose_track")
-                            .with_showoff(Showoff::new(vec![ComplexAnimPart {
-                                name: "drink_track".to_string(),
-                                repeat: 1,
-                                speed: 1.0,
-                                wait: Duration::from_millis(0),
-                            }])),
+                        super::anim_player(model_def, anim_player),
                         Animations::new(gltf.named_animations.clone()),
-                        ProjectileConfig {
-                            transform: Transform::from_translation(Vec3::new(
-                                0.0, 2.44231, -2.36796,
-                            )),
-                            color: Color::CYAN,
-                            radius: 0.25,
-                            ..Default::default()
-                        },
+                        super::projectile_config(model_def),
                     ));
                 }
             }
@@ -126,14 +109,11 @@ fn filter_animations(
         let current_anim = anims.current();
 
         for (name, mut visibility) in named.iter_mut() {
-            match name.as_str() {
-                "teacup" => {
-                    *visibility = match current_anim.as_str() {
-                        "attack_track" => Visibility::Hidden,
-                        _ => Visibility::Inherited,
-                    }
+            if name.as_str() == "teacup" {
+                *visibility = match current_anim.as_str() {
+                    "attack_track" => Visibility::Hidden,
+                    _ => Visibility::Inherited,
                 }
-                _ => {}
             }
         }
     }
@@ -141,7 +121,8 @@ fn filter_animations(
 
 fn on_avatar(mut query: Query<(&mut ComplexAnimPlayer, &mut avatars::HeroState), With<Nulch>>) {
     for (mut anim_player, mut state) in query.iter_mut() {
-        let origin = Vec3::new(0.0, 2.3, 0.75);
+        let model_def = super::model_def("nulch");
+        let origin = Vec3::from(model_def.camera);
         let target = origin.xy().extend(0.0);
         state.camera = match state.location {
             AvatarLocation::Thumbnail => {
@@ -175,19 +156,17 @@ fn on_arena(
     for (entity, arena_state, id, visibility) in query.iter() {
         for modifier in &arena_state.modifiers {
             match modifier {
-                Modifier::NormalAttack => {
-                    if visibility.get() {
-                        commands.entity(entity).with_children(|p| {
-                            p.spawn(AudioBundle {
-                                source: asset_server.load("embedded://shoot5.ogg"),
-                                settings: PlaybackSettings {
-                                    // mode: PlaybackMode::Despawn,
-                                    volume: Volume::new(MASTER_VOLUME),
-                                    ..Default::default()
-                                },
-                            });
+                Modifier::NormalAttack if visibility.get() => {
+                    commands.entity(entity).with_children(|p| {
+                        p.spawn(AudioBundle {
+                            source: asset_server.load(&super::model_def("nulch").shoot_sound),
+                            settings: PlaybackSettings {
+                                // mode: PlaybackMode::Despawn,
+                                volume: Volume::new(MASTER_VOLUME),
+                                ..Default::default()
+                            },
                         });
-                    }
+                    });
                 }
                 Modifier::ShootHealBeam => {
                     let offset = transforms.get(entity).unwrap().translation();
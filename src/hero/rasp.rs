@@ -1,20 +1,12 @@
-use std::{
-    f32::consts::{FRAC_PI_6, SQRT_2},
-    time::Duration,
-};
+use std::f32::consts::{FRAC_PI_6, SQRT_2};
 
-use bevy::{
-    audio::{PlaybackMode, Volume},
-    gltf::Gltf,
-    prelude::*,
-    utils::HashMap,
-};
+use bevy::{audio::Volume, gltf::Gltf, prelude::*, utils::HashMap};
 
 use crate::{
     battle::{ability::fire_cube::CUBE_SIDE, modifier::Modifier},
     component::{
         arena,
-        complex_anim_player::{self, Animations, ComplexAnimPart, ComplexAnimPlayer, Showoff},
+        complex_anim_player::{self, Animations, ComplexAnimPlayer},
         fight_state::FightState,
         model::Model,
         projectile::{Projectile, ProjectileConfig},
@@ -78,13 +70,15 @@ fn on_add(
         return;
     }
 
+    let model_def = super::model_def("rasp");
+
     let gltf = match model {
         Some(model) => match assets_gltf.get(&model.handle) {
             Some(gltf) => gltf,
             None => return,
         },
         None => {
-            commands.insert_resource(Model::<Rasp>::new(asset_server.load("embedded://rasp.glb")));
+            commands.insert_resource(Model::<Rasp>::new(asset_server.load(&model_def.gltf)));
             return;
         }
     };
@@ -97,17 +91,12 @@ fn on_add(
                 State {
                     fires: HashMap::new(),
                 },
-                ProjectileConfig {
-                    transform: Transform::from_translation(Vec3::new(0.0359386, 1.85802, 1.39652)),
-                    color: Color::PINK,
-                    radius: 0.05,
-                    ..Default::default()
-                },
+                super::projectile_config(model_def),
             ))
             .with_children(|p| {
                 p.spawn(SceneBundle {
                     scene: gltf.scenes[0].clone(),
-                    transform: Transform::from_scale(Vec3::splat(0.1)),
+                    transform: Transform::from_scale(Vec3::splat(model_def.scale)),
                     ..Default::default()
                 });
                 p.spawn((
@@ -125,25 +114,7 @@ fn on_add(
             for parent in children.iter_ancestors(anim_player) {
                 if parent == entity.id() {
                     entity.insert((
-                        ComplexAnimPlayer::new(anim_player)
-                            .with_idle("idle_track")
-                            .with_attack("shoot_track", 20)
-                            .with_win("win_track")
-                            .with_lose("lose_track")
-                            .with_showoff(Showoff::new(vec![
-                                ComplexAnimPart {
-                                    name: "start_shoot_track".to_string(),
-                                    repeat: 1,
-                                    speed: 1.0,
-                                    wait: Duration::from_millis(1000),
-                                },
-                                ComplexAnimPart {
-                                    name: "shoot_track".to_string(),
-                                    repeat: 3,
-                                    speed: 3.0,
-                                    wait: Duration::from_millis(500),
-                                },
-                            ])),
+                        super::anim_player(model_def, anim_player),
                         Animations::new(gltf.named_animations.clone()),
                     ));
                 }
@@ -172,15 +143,15 @@ fn filter_animations(
 
 fn on_avatar(mut query: Query<(&mut ComplexAnimPlayer, &mut avatars::HeroState), With<Rasp>>) {
     for (mut anim_player, mut state) in query.iter_mut() {
-        let origin = Vec3::new(-0.15, 2.1, 1.0);
+        let model_def = super::model_def("rasp");
+        let origin = Vec3::from(model_def.camera);
         let target = origin.xy().extend(0.0);
         state.camera = match state.location {
             AvatarLocation::Thumbnail => {
                 Transform::from_translation(origin).looking_at(target, Vec3::Y)
             }
             _ => {
-                let origin = Vec3::new(0.0, 2.2, 1.0);
-                let target = origin.xy().extend(0.2);
+                let (origin, target) = super::close_up(model_def).unwrap_or((origin, target));
                 anim_player.play(false, complex_anim_player::State::Idle);
                 Transform::from_translation(
                     target + Quat::from_rotation_y(-FRAC_PI_6) * (origin - target),
@@ -225,19 +196,17 @@ fn on_arena(
 
         for modifier in &arena_state.modifiers {
             match modifier {
-                Modifier::NormalAttack => {
-                    if visibility.get() {
-                        commands.entity(entity).with_children(|p| {
-                            p.spawn(AudioBundle {
-                                source: asset_server.load("embedded://shoot6.ogg"),
-                                settings: PlaybackSettings {
-                                    // mode: PlaybackMode::Despawn,
-                                    volume: Volume::new(MASTER_VOLUME),
-                                    ..Default::default()
-                                },
-                            });
+                Modifier::NormalAttack if visibility.get() => {
+                    commands.entity(entity).with_children(|p| {
+                        p.spawn(AudioBundle {
+                            source: asset_server.load(&super::model_def("rasp").shoot_sound),
+                            settings: PlaybackSettings {
+                                // mode: PlaybackMode::Despawn,
+                                volume: Volume::new(MASTER_VOLUME),
+                                ..Default::default()
+                            },
                         });
-                    }
+                    });
                 }
                 Modifier::SpawnFireCube(i) => {
                     commands.entity(cube).with_children(|p| {
@@ -1,28 +1,62 @@
+// bevy systems take their queries and resources as arguments
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
+use battle::replay::Replay;
+use battle_bridge::{
+    BattleResource, DifficultyResource, NetClient, NetInbox, ReplayPlayback, ReplayRecorder,
+    RoundCaptureResource,
+};
 use bevy::{
     app::MainScheduleOrder,
     audio::{PlaybackMode, Volume},
     prelude::*,
-    window::{WindowMode, WindowResolution},
+    window::WindowMode,
 };
 use bevy_embedded_assets::EmbeddedAssetPlugin;
 use bevy_hanabi::prelude::*;
 use component::ComponentsPlugin;
 use hero::HeroesPlugin;
 use iyes_perf_ui::prelude::*;
-use scene::ScenesPlugin;
+use scene::{
+    landing::{HeroSelected, HeroWatch, HumanPlayers},
+    GameState, ScenesPlugin,
+};
 use ui::UIPlugin;
 
 mod battle;
 mod battle_bridge;
 mod component;
 mod hero;
+mod net;
 mod scene;
+mod simulate;
 mod ui;
 
 pub const MASTER_VOLUME: f32 = 0.1;
 pub const BUTTON_VOLUME: f32 = 0.05;
 
 fn main() {
+    if let Err(err) = battle::data::check() {
+        eprintln!("Failed to load game data: {err}");
+        std::process::exit(1);
+    }
+
+    if let Some(config) = simulate::Config::from_args() {
+        if let Err(err) = config.and_then(simulate::run) {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(config) = net::server::Config::from_args() {
+        if let Err(err) = config.and_then(net::server::run) {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let mut app = App::new();
 
     app.add_schedule(Schedule::new(ui::LocalSchedule))
@@ -63,8 +97,72 @@ fn main() {
     .add_plugins((PerfUiPlugin, bevy::diagnostic::FrameTimeDiagnosticsPlugin))
     // .add_plugins(bevy_inspector_egui::quick::WorldInspectorPlugin::new())
     .add_systems(Update, bevy::window::close_on_esc)
-    .add_systems(Startup, init)
-    .run();
+    .add_systems(Startup, init);
+
+    app.init_resource::<DifficultyResource>();
+
+    app.insert_resource(HumanPlayers::new(
+        arg("--hotseat").and_then(|n| n.parse().ok()).unwrap_or(1),
+    ));
+
+    if let Some(addr) = arg("--connect") {
+        let Some(hero) = arg("--hero") else {
+            eprintln!("--connect needs --hero");
+            std::process::exit(1);
+        };
+        let client = match net::client::Client::connect(&addr, &hero) {
+            Ok(client) => client,
+            Err(err) => {
+                eprintln!("{addr}: {err}");
+                std::process::exit(1);
+            }
+        };
+        app.insert_resource(NetClient(client))
+            .init_resource::<NetInbox>()
+            .add_systems(
+                Update,
+                battle_bridge::receive.run_if(resource_exists::<NetClient>),
+            )
+            .insert_resource(HumanPlayers {
+                seats: 1,
+                ids: vec![hero.clone()],
+                turn: 0,
+            })
+            .insert_resource(HeroSelected { id: hero.clone() })
+            .insert_resource(HeroWatch { id: hero })
+            .insert_state(GameState::Landing);
+    }
+
+    if let Some(dir) = arg("--record") {
+        app.insert_resource(ReplayRecorder(dir.into()));
+    }
+
+    if let Some(path) = arg("--replay") {
+        let (battle, replay) =
+            match Replay::load(&path).map(|replay| (replay.battle(), replay)) {
+                Ok(loaded) => loaded,
+                Err(err) => {
+                    eprintln!("{path}: {err}");
+                    std::process::exit(1);
+                }
+            };
+        app.insert_resource(BattleResource(battle))
+            .insert_resource(RoundCaptureResource(replay.captures))
+            .insert_resource(HeroSelected {
+                id: replay.watch.clone(),
+            })
+            .insert_resource(HeroWatch { id: replay.watch })
+            .insert_resource(ReplayPlayback)
+            .insert_state(GameState::FightArena);
+    }
+
+    app.run();
+}
+
+fn arg(name: &str) -> Option<String> {
+    let mut args = std::env::args();
+    args.find(|arg| arg == name)?;
+    args.next()
 }
 
 fn init(mut commands: Commands, asset_server: Res<AssetServer>) {
@@ -0,0 +1,53 @@
+use std::{
+    error::Error,
+    net::{TcpStream, ToSocketAddrs},
+    sync::{
+        mpsc::{self, Receiver},
+        Mutex,
+    },
+};
+
+use super::{ClientMessage, ServerMessage};
+
+pub struct Client {
+    stream: TcpStream,
+    messages: Mutex<Receiver<((), Option<ServerMessage>)>>,
+}
+
+impl Client {
+    pub fn connect(addr: impl ToSocketAddrs, hero: &str) -> Result<Self, Box<dyn Error>> {
+        let stream = TcpStream::connect(addr)?;
+        let (tx, rx) = mpsc::channel();
+        super::spawn_reader(&stream, (), tx)?;
+        let client = Self {
+            stream,
+            messages: Mutex::new(rx),
+        };
+        client.send(&ClientMessage::Join(hero.to_string()))?;
+        Ok(client)
+    }
+
+    pub fn send(&self, message: &ClientMessage) -> Result<(), Box<dyn Error>> {
+        super::send(&self.stream, message)
+    }
+
+    /// Blocks until the next message, `None` when the server is gone
+    #[cfg(test)]
+    pub fn recv(&self) -> Option<ServerMessage> {
+        self.messages
+            .lock()
+            .unwrap()
+            .recv()
+            .ok()
+            .and_then(|(_, m)| m)
+    }
+
+    pub fn try_recv(&self) -> Option<ServerMessage> {
+        self.messages
+            .lock()
+            .unwrap()
+            .try_recv()
+            .ok()
+            .and_then(|(_, m)| m)
+    }
+}
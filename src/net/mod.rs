@@ -0,0 +1,73 @@
+use std::{
+    error::Error,
+    io::{BufRead, BufReader, Write},
+    net::TcpStream,
+    sync::mpsc::Sender,
+    thread,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::battle::{save::SaveGame, RoundCapture};
+
+pub mod client;
+pub mod server;
+
+/// Intents a player sends during the shop phase, one JSON object per line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ClientMessage {
+    Join(String),
+    BuyCard(usize),
+    SellCard(usize),
+    Reroll,
+    SetCardsLocked(bool),
+    PickItem(usize),
+    CombineItems(usize, usize),
+    Ready,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ServerMessage {
+    /// Whole battle as the server sees it, sent on every change in the shop
+    Shop(Box<SaveGame>),
+    Round(Vec<RoundCapture>),
+    GameEnded,
+    Error(String),
+}
+
+pub fn send<T: Serialize>(mut stream: &TcpStream, message: &T) -> Result<(), Box<dyn Error>> {
+    let mut line = serde_json::to_string(message)?;
+    line.push('\n');
+    stream.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// Forwards every message read from the stream, `None` once the peer is gone
+fn spawn_reader<T, K>(
+    stream: &TcpStream,
+    key: K,
+    tx: Sender<(K, Option<T>)>,
+) -> Result<(), Box<dyn Error>>
+where
+    T: DeserializeOwned + Send + 'static,
+    K: Copy + Send + 'static,
+{
+    let reader = BufReader::new(stream.try_clone()?);
+    thread::spawn(move || {
+        for line in reader.lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            match serde_json::from_str(&line) {
+                Ok(message) => {
+                    if tx.send((key, Some(message))).is_err() {
+                        return;
+                    }
+                }
+                Err(err) => eprintln!("bad message {line}: {err}"),
+            }
+        }
+        let _ = tx.send((key, None));
+    });
+    Ok(())
+}
@@ -0,0 +1,261 @@
+use std::{
+    error::Error,
+    net::{TcpListener, TcpStream},
+    path::Path,
+    sync::mpsc::{self, RecvTimeoutError},
+    time::{Duration, Instant},
+};
+
+use rand::random;
+
+use crate::battle::{
+    bot::Difficulty, hero, player::Player, ruleset::Ruleset, save::SaveGame, Battle,
+};
+
+use super::{ClientMessage, ServerMessage};
+
+const MAX_ROUNDS: u32 = 100;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub addr: String,
+    pub players: usize,
+    pub shop_time: f32,
+    pub max_rounds: u32,
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    pub ruleset: Ruleset,
+}
+
+impl Config {
+    pub fn from_args() -> Option<Result<Self, Box<dyn Error>>> {
+        let mut args = std::env::args().skip(1);
+        if !args.any(|arg| arg == "--server") {
+            return None;
+        }
+        Some(Self::parse(std::env::args().skip(1)))
+    }
+
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut config = Self {
+            addr: "0.0.0.0:7878".to_string(),
+            players: 2,
+            shop_time: 60.0,
+            max_rounds: MAX_ROUNDS,
+            seed: random(),
+            difficulty: Difficulty::default(),
+            ruleset: Ruleset::default(),
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {arg}"));
+            match arg.as_str() {
+                "--server" => config.addr = value()?,
+                "--players" => config.players = value()?.parse()?,
+                "--shop-time" => config.shop_time = value()?.parse()?,
+                "--rounds" => config.max_rounds = value()?.parse()?,
+                "--seed" => config.seed = value()?.parse()?,
+                "--difficulty" => config.difficulty = value()?.parse()?,
+                "--ruleset" => config.ruleset = Ruleset::load(Path::new(&value()?))?,
+                other => return Err(format!("unknown argument {other}").into()),
+            }
+        }
+        if !(1..=6).contains(&config.players) {
+            return Err(format!("{} players, expected 1 to 6", config.players).into());
+        }
+        Ok(config)
+    }
+}
+
+struct Seat {
+    stream: TcpStream,
+    hero: Option<&'static str>,
+    connected: bool,
+    ready: bool,
+}
+
+fn broadcast(seats: &mut [Seat], message: &ServerMessage) {
+    for seat in seats.iter_mut().filter(|s| s.connected) {
+        if super::send(&seat.stream, message).is_err() {
+            seat.connected = false;
+        }
+    }
+}
+
+pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind(&config.addr)?;
+    println!("listening on {}", listener.local_addr()?);
+    serve(listener, &config)
+}
+
+/// Owns the battle: waits for every player to join, then plays rounds until one hero is left
+pub fn serve(listener: TcpListener, config: &Config) -> Result<(), Box<dyn Error>> {
+    let (tx, rx) = mpsc::channel();
+    let mut seats = vec![];
+    for (index, stream) in listener.incoming().take(config.players).enumerate() {
+        let stream = stream?;
+        super::spawn_reader(&stream, index, tx.clone())?;
+        seats.push(Seat {
+            stream,
+            hero: None,
+            connected: true,
+            ready: false,
+        });
+    }
+
+    while seats.iter().any(|s| s.hero.is_none()) {
+        let (index, message) = rx.recv()?;
+        match message {
+            None => return Err(format!("player {index} left before joining").into()),
+            Some(ClientMessage::Join(id)) => {
+                let taken = seats.iter().any(|s| s.hero == Some(id.as_str()));
+                match hero::by_id(&id) {
+                    Some(hero) if !taken && seats[index].hero.is_none() => {
+                        seats[index].hero = Some(hero.id)
+                    }
+                    _ => {
+                        let error = ServerMessage::Error(format!("can't join as {id}"));
+                        super::send(&seats[index].stream, &error)?;
+                    }
+                }
+            }
+            Some(_) => {}
+        }
+    }
+
+    let humans = seats
+        .iter()
+        .filter_map(|s| s.hero.map(String::from))
+        .collect::<Vec<_>>();
+    let players = hero::all()
+        .into_iter()
+        .map(|hero| Player::with_ruleset(hero, &config.ruleset))
+        .collect();
+    let mut battle = Battle::with_ruleset(players, config.seed, config.ruleset.clone());
+    battle.difficulty = config.difficulty;
+
+    loop {
+        let alive = battle.players.iter().filter(|p| p.hp > 0).count();
+        if alive <= 1 || battle.round > config.max_rounds {
+            broadcast(&mut seats, &ServerMessage::GameEnded);
+            return Ok(());
+        }
+
+        broadcast(
+            &mut seats,
+            &ServerMessage::Shop(Box::new(SaveGame::new(&battle, &humans, 0))),
+        );
+        for seat in &mut seats {
+            let hero = seat.hero.unwrap();
+            seat.ready = !seat.connected || battle.player_by_id(hero).hp <= 0;
+        }
+
+        let deadline = Instant::now() + Duration::from_secs_f32(config.shop_time);
+        while !seats.iter().all(|s| s.ready) {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            let (index, message) = match rx.recv_timeout(timeout) {
+                Ok(received) => received,
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(err) => return Err(err.into()),
+            };
+            let seat = &mut seats[index];
+            let hero = seat.hero.unwrap();
+            match message {
+                // a bot takes over the seat
+                None => {
+                    seat.connected = false;
+                    seat.ready = true;
+                    continue;
+                }
+                Some(ClientMessage::Ready) => {
+                    seat.ready = true;
+                    continue;
+                }
+                Some(_) if seat.ready => continue,
+                Some(ClientMessage::BuyCard(index)) => battle.buy_card(hero, index),
+                Some(ClientMessage::SellCard(index)) => battle.sell_card(hero, index),
+                Some(ClientMessage::Reroll) => battle.reroll(hero),
+                Some(ClientMessage::SetCardsLocked(locked)) => {
+                    battle.set_cards_locked(hero, locked)
+                }
+                Some(ClientMessage::PickItem(index)) => battle.pick_item(hero, index),
+                Some(ClientMessage::CombineItems(first, second)) => {
+                    battle.combine_items(hero, first, second)
+                }
+                Some(ClientMessage::Join(_)) => continue,
+            }
+            broadcast(
+                &mut seats,
+                &ServerMessage::Shop(Box::new(SaveGame::new(&battle, &humans, 0))),
+            );
+        }
+
+        let bots = battle
+            .players
+            .iter()
+            .map(|p| p.hero.id)
+            .filter(|id| !seats.iter().any(|s| s.connected && s.hero == Some(*id)))
+            .collect::<Vec<_>>();
+        for id in bots {
+            battle.ai(id);
+        }
+
+        let captures = battle.round();
+        broadcast(&mut seats, &ServerMessage::Round(captures));
+        battle.apply();
+    }
+}
+
+#[test]
+fn localhost() {
+    use std::thread;
+
+    use super::client::Client;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let config = Config {
+        addr: addr.to_string(),
+        players: 3,
+        shop_time: 10.0,
+        max_rounds: 2,
+        seed: 1,
+        difficulty: Difficulty::Easy,
+        ruleset: Ruleset::default(),
+    };
+    let server = thread::spawn(move || serve(listener, &config).unwrap());
+
+    let clients = ["rasp", "duck", "nulch"].map(|hero| {
+        thread::spawn(move || {
+            let client = Client::connect(addr, hero).unwrap();
+            let (mut rounds, mut acted, mut last) = (0, false, None);
+            while let Some(message) = client.recv() {
+                match message {
+                    ServerMessage::Shop(save) => {
+                        if !acted {
+                            client.send(&ClientMessage::BuyCard(0)).unwrap();
+                            client.send(&ClientMessage::Ready).unwrap();
+                            acted = true;
+                        }
+                        last = Some(save);
+                    }
+                    ServerMessage::Round(captures) => {
+                        assert_eq!(captures.len(), 3);
+                        rounds += 1;
+                        acted = false;
+                    }
+                    ServerMessage::GameEnded => break,
+                    ServerMessage::Error(err) => panic!("{err}"),
+                }
+            }
+            let mut battle = last.unwrap().battle();
+            (rounds, battle.player_by_id(hero).cards.len())
+        })
+    });
+
+    for client in clients {
+        let (rounds, cards) = client.join().unwrap();
+        assert_eq!(rounds, 2);
+        assert!(cards >= 1);
+    }
+    server.join().unwrap();
+}
@@ -13,7 +13,7 @@ use bevy::{
 use crate::{
     battle::RoundCapture,
     battle_bridge::{HeroesResource, RoundCaptureResource},
-    hero::{HeroId, HeroesRoot},
+    hero::{HeroId, HeroesRoot, WithOpponents},
 };
 
 use super::{landing::HeroWatch, InvalidateTree, LocalSchedule};
@@ -150,8 +150,8 @@ fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>, heroes: Res<
     ));
 
     commands.spawn((HeroesRoot, Avatar::Thumbnail));
-    commands.spawn((HeroesRoot, Avatar::Left));
-    commands.spawn((HeroesRoot, Avatar::Right));
+    commands.spawn((HeroesRoot, WithOpponents, Avatar::Left));
+    commands.spawn((HeroesRoot, WithOpponents, Avatar::Right));
 
     let light = |layer| {
         (
@@ -301,9 +301,8 @@ fn update_fight(
     let mut camera_right = camera_right.single_mut();
 
     for (entity, avatar, children) in query.iter() {
-        match avatar {
-            Avatar::Thumbnail => continue,
-            _ => {}
+        if let Avatar::Thumbnail = avatar {
+            continue;
         }
 
         let round = round.by_player(&watch.id).unwrap();
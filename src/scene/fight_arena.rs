@@ -3,19 +3,24 @@ use std::{error::Error, f32::consts::PI};
 use bevy::{core_pipeline::bloom::BloomSettings, prelude::*};
 
 use crate::{
-    battle::fight::DURATION,
-    battle_bridge::{BattleResource, RoundCaptureResource},
+    battle::{fight::DURATION, replay::Replay},
+    battle_bridge::{
+        BattleResource, NetInbox, ReplayPlayback, ReplayRecorder, RoundCaptureResource,
+    },
     component::{arena::Arena, game_timer::GameTimer},
-    hero::HeroesRoot,
+    hero::{HeroesRoot, WithOpponents},
     scene::UiRoot,
     ui::fight_arena_layout::FightArenaLayout,
 };
 
 use super::{
-    landing::{HeroSelected, HeroWatch},
+    landing::{HeroSelected, HumanPlayers},
     GameState, LocalSchedule, Root,
 };
 
+// time to look at the damage breakdown before going home
+const BREAKDOWN_DURATION: f32 = 8.0;
+
 #[derive(Resource)]
 struct State {}
 
@@ -35,9 +40,20 @@ fn init(
     mut commands: Commands,
     mut game_timer: ResMut<GameTimer>,
     root: Query<Entity, Added<Root>>,
+    battle: Res<BattleResource>,
+    capture: Res<RoundCaptureResource>,
+    selected: Res<HeroSelected>,
+    recorder: Option<Res<ReplayRecorder>>,
 ) -> Result<(), Box<dyn Error>> {
     let root = root.get_single()?;
 
+    if let Some(recorder) = recorder {
+        let path = recorder.0.join(format!("round_{:02}.ron", battle.round));
+        if let Err(err) = Replay::new(&battle, &selected.id, capture.0.clone()).save(&path) {
+            error!("Failed to save replay {}: {err}", path.display());
+        }
+    }
+
     commands.entity(root).with_children(|p| {
         p.spawn((
             Camera3dBundle {
@@ -68,7 +84,7 @@ fn init(
             ..Default::default()
         });
 
-        p.spawn((Arena {}, HeroesRoot));
+        p.spawn((Arena {}, HeroesRoot, WithOpponents));
     });
 
     commands.spawn((UiRoot, FightArenaLayout));
@@ -85,29 +101,51 @@ fn update(
     mut next_state: ResMut<NextState<GameState>>,
     mut game_timer: ResMut<GameTimer>,
     mut battle: ResMut<BattleResource>,
-    selected: Res<HeroSelected>,
+    mut humans: ResMut<HumanPlayers>,
+    playback: Option<Res<ReplayPlayback>>,
+    mut inbox: Option<ResMut<NetInbox>>,
 ) {
     if game_timer.fired {
         if game_timer.red {
+            // online the next shop comes from the server
+            if let Some(inbox) = inbox.as_deref_mut() {
+                if let Some(save) = inbox.shop.take() {
+                    battle.0 = save.battle();
+                    commands.remove_resource::<RoundCaptureResource>();
+                    next_state.set(GameState::FightHome);
+                } else if inbox.ended {
+                    commands.remove_resource::<RoundCaptureResource>();
+                    next_state.set(GameState::GameEnded);
+                }
+                return;
+            }
+
             game_timer.restart(99999.0, false);
 
-            commands.insert_resource(HeroWatch {
-                id: selected.id.clone(),
-            });
+            if playback.is_some() {
+                commands.remove_resource::<ReplayPlayback>();
+                commands.remove_resource::<RoundCaptureResource>();
+                commands.remove_resource::<BattleResource>();
+                next_state.set(GameState::Splash);
+                return;
+            }
+
             commands.remove_resource::<RoundCaptureResource>();
             battle.apply();
             for player in battle
                 .players
                 .iter()
                 .map(|p| p.hero.id)
-                .filter(|id| *id != selected.id)
+                .filter(|id| !humans.contains(id))
                 .collect::<Vec<_>>()
             {
                 battle.ai(player);
             }
-            next_state.set(GameState::FightHome);
+            humans.first_turn(&battle);
+            humans.select(&mut commands);
+            next_state.set(humans.shop_state());
         } else {
-            game_timer.restart(3.0, true);
+            game_timer.restart(BREAKDOWN_DURATION, true);
         }
     }
 }
@@ -3,17 +3,16 @@ use std::error::Error;
 use bevy::{core_pipeline::bloom::BloomSettings, prelude::*};
 
 use crate::{
-    battle_bridge::{BattleResource, RoundCaptureResource},
+    battle::save::SaveGame,
+    battle_bridge::{BattleResource, NetClient, NetInbox, RoundCaptureResource},
     component::{game_timer::GameTimer, home::Home},
     hero::HeroesRoot,
+    net::ClientMessage,
     scene::UiRoot,
     ui::fight_home_layout::FightHomeLayout,
 };
 
-use super::{
-    landing::{HeroSelected, HeroWatch},
-    GameState, LocalSchedule, Root,
-};
+use super::{landing::HumanPlayers, GameState, LocalSchedule, Root};
 
 #[derive(Resource)]
 struct State {}
@@ -24,7 +23,14 @@ impl Plugin for FightHome {
     fn build(&self, app: &mut App) {
         app.add_systems(
             LocalSchedule,
-            (init.map(drop), update.run_if(resource_exists::<State>))
+            (
+                init.map(drop),
+                (
+                    update,
+                    save_on_exit.run_if(not(resource_exists::<NetClient>)),
+                )
+                    .run_if(resource_exists::<State>),
+            )
                 .run_if(in_state(GameState::FightHome)),
         );
     }
@@ -33,9 +39,17 @@ impl Plugin for FightHome {
 fn init(
     mut commands: Commands,
     mut game_timer: ResMut<GameTimer>,
+    battle: Res<BattleResource>,
     root: Query<Entity, Added<Root>>,
+    humans: Res<HumanPlayers>,
+    net: Option<Res<NetClient>>,
 ) -> Result<(), Box<dyn Error>> {
     let root = root.get_single()?;
+    if net.is_none() {
+        if let Err(err) = SaveGame::new(&battle, &humans.ids, humans.turn).save() {
+            error!("Failed to save the game: {err}");
+        }
+    }
     commands.entity(root).with_children(|p| {
         p.spawn((
             Camera3dBundle {
@@ -84,8 +98,26 @@ fn update(
     mut next_state: ResMut<NextState<GameState>>,
     mut battle: ResMut<BattleResource>,
     mut game_timer: ResMut<GameTimer>,
-    selected: Res<HeroSelected>,
+    mut humans: ResMut<HumanPlayers>,
+    mut inbox: Option<ResMut<NetInbox>>,
+    net: Option<Res<NetClient>>,
 ) {
+    if let Some(inbox) = inbox.as_deref_mut() {
+        if let Some(save) = inbox.shop.take() {
+            battle.0 = save.battle();
+        }
+        if let Some(captures) = inbox.round.take() {
+            game_timer.restart(99999.0, false);
+            commands.insert_resource(RoundCaptureResource(captures));
+            next_state.set(GameState::FightArena);
+            return;
+        }
+        if inbox.ended {
+            next_state.set(GameState::GameEnded);
+            return;
+        }
+    }
+
     let mut players = battle.players.clone();
     players.retain(|p| p.hp > 0);
     if players.len() == 1 {
@@ -95,10 +127,21 @@ fn update(
             if game_timer.red {
                 game_timer.restart(99999.0, false);
 
+                // the server runs the round once everybody is ready
+                if let Some(net) = net {
+                    net.send(ClientMessage::Ready);
+                    return;
+                }
+
+                if humans.next_turn(&battle) {
+                    humans.select(&mut commands);
+                    next_state.set(GameState::HandOff);
+                    return;
+                }
+
+                humans.first_turn(&battle);
+                humans.select(&mut commands);
                 commands.insert_resource(RoundCaptureResource(battle.round()));
-                commands.insert_resource(HeroWatch {
-                    id: selected.id.clone(),
-                });
                 next_state.set(GameState::FightArena);
             } else {
                 game_timer.restart(3.0, true);
@@ -106,3 +149,16 @@ fn update(
         }
     }
 }
+
+// keeps the cards bought this round when the game is closed with Esc
+fn save_on_exit(
+    battle: Res<BattleResource>,
+    humans: Res<HumanPlayers>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        if let Err(err) = SaveGame::new(&battle, &humans.ids, humans.turn).save() {
+            error!("Failed to save the game: {err}");
+        }
+    }
+}
@@ -2,7 +2,11 @@ use std::error::Error;
 
 use bevy::{core_pipeline::bloom::BloomSettings, prelude::*};
 
-use crate::scene::UiRoot;
+use crate::{
+    battle::save::SaveGame,
+    battle_bridge::{NetClient, NetInbox},
+    scene::UiRoot,
+};
 
 use super::{GameState, LocalSchedule, Root};
 
@@ -30,6 +34,9 @@ fn init(
 ) -> Result<(), Box<dyn Error>> {
     let root = root.get_single()?;
     commands.insert_resource(State { timer: 0.0 });
+    commands.remove_resource::<NetClient>();
+    commands.remove_resource::<NetInbox>();
+    SaveGame::remove()?;
 
     commands.entity(root).with_children(|p| {
         p.spawn((
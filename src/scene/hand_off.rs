@@ -0,0 +1,81 @@
+use std::error::Error;
+
+use bevy::{core_pipeline::bloom::BloomSettings, prelude::*};
+
+use crate::{battle_bridge::BattleResource, scene::UiRoot};
+
+use super::{landing::HeroSelected, GameState, LocalSchedule, Root};
+
+// hot-seat screen between shop turns, so players don't see each other's cards
+pub struct HandOff;
+
+impl Plugin for HandOff {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            LocalSchedule,
+            (init.map(drop), update).run_if(in_state(GameState::HandOff)),
+        );
+    }
+}
+
+fn init(
+    mut commands: Commands,
+    root: Query<Entity, Added<Root>>,
+    asset_server: Res<AssetServer>,
+    battle: Res<BattleResource>,
+    selected: Res<HeroSelected>,
+) -> Result<(), Box<dyn Error>> {
+    let root = root.get_single()?;
+
+    commands.entity(root).with_children(|p| {
+        p.spawn((
+            Camera3dBundle {
+                camera: Camera {
+                    hdr: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            BloomSettings::default(),
+        ));
+    });
+
+    let name = battle
+        .players
+        .iter()
+        .find(|p| p.hero.id == selected.id)
+        .map_or("", |p| p.hero.name);
+
+    commands
+        .spawn((
+            UiRoot,
+            NodeBundle {
+                style: Style {
+                    width: Val::Vw(100.0),
+                    height: Val::Vh(100.0),
+                    display: Display::Flex,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ))
+        .with_children(|p| {
+            p.spawn(TextBundle::from_section(
+                format!("Раунд {}\nХодит {name}\n\nEnter - начать ход", battle.round),
+                TextStyle {
+                    font: asset_server.load("embedded://comic.ttf"),
+                    font_size: 50.0,
+                    ..Default::default()
+                },
+            ));
+        });
+    Ok(())
+}
+
+fn update(mut next_state: ResMut<NextState<GameState>>, keyboard_input: Res<ButtonInput<KeyCode>>) {
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        next_state.set(GameState::FightHome);
+    }
+}
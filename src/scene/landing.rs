@@ -3,8 +3,8 @@ use std::error::Error;
 use bevy::{core_pipeline::bloom::BloomSettings, prelude::*};
 
 use crate::{
-    battle::{player::Player, Battle},
-    battle_bridge::{BattleResource, HeroesResource},
+    battle::{bot::Strategy, player::Player, Battle},
+    battle_bridge::{BattleResource, DifficultyResource, HeroesResource, NetInbox},
     component::land::Land,
     hero::HeroesRoot,
 };
@@ -22,7 +22,13 @@ impl Plugin for Landing {
     fn build(&self, app: &mut App) {
         app.add_systems(
             LocalSchedule,
-            (init.map(drop), update.run_if(resource_exists::<State>))
+            (
+                init.map(drop),
+                update
+                    .run_if(resource_exists::<State>)
+                    .run_if(not(resource_exists::<NetInbox>)),
+                wait_for_server.run_if(resource_exists::<NetInbox>),
+            )
                 .run_if(in_state(GameState::Landing)),
         );
     }
@@ -38,6 +44,64 @@ pub struct HeroWatch {
     pub id: String,
 }
 
+/// Heroes played by people sharing this machine, the shop turn goes around them
+#[derive(Resource)]
+pub struct HumanPlayers {
+    pub seats: usize,
+    pub ids: Vec<String>,
+    pub turn: usize,
+}
+
+impl HumanPlayers {
+    pub fn new(seats: usize) -> Self {
+        Self {
+            seats: seats.clamp(1, 6),
+            ids: vec![],
+            turn: 0,
+        }
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.iter().any(|human| human == id)
+    }
+
+    fn alive(battle: &Battle, id: &str) -> bool {
+        battle.players.iter().any(|p| p.hero.id == id && p.hp > 0)
+    }
+
+    /// Falls back to the first human when everybody is dead, so there is still someone to watch
+    pub fn first_turn(&mut self, battle: &Battle) {
+        self.turn = (0..self.ids.len())
+            .find(|&turn| Self::alive(battle, &self.ids[turn]))
+            .unwrap_or(0);
+    }
+
+    /// Moves to the next alive human, false when everybody has had their turn
+    pub fn next_turn(&mut self, battle: &Battle) -> bool {
+        match (self.turn + 1..self.ids.len()).find(|&turn| Self::alive(battle, &self.ids[turn])) {
+            Some(turn) => {
+                self.turn = turn;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn select(&self, commands: &mut Commands) {
+        let id = self.ids[self.turn].clone();
+        commands.insert_resource(HeroSelected { id: id.clone() });
+        commands.insert_resource(HeroWatch { id });
+    }
+
+    /// Several people get a hand-off screen so they don't see each other's shop
+    pub fn shop_state(&self) -> GameState {
+        match self.ids.len() {
+            0 | 1 => GameState::FightHome,
+            _ => GameState::HandOff,
+        }
+    }
+}
+
 fn init(mut commands: Commands, root: Query<Entity, Added<Root>>) -> Result<(), Box<dyn Error>> {
     let root = root.get_single()?;
     commands.insert_resource(State { timer: 0.0 });
@@ -76,17 +140,34 @@ fn update(
     mut commands: Commands,
     mut next_state: ResMut<NextState<GameState>>,
     mut state: ResMut<State>,
-    heroes: Res<HeroesResource>,
+    mut humans: ResMut<HumanPlayers>,
+    (heroes, difficulty): (Res<HeroesResource>, Res<DifficultyResource>),
     time: Res<Time>,
     land: Query<&Land>,
 ) {
     if land.single().ready() {
         state.timer += time.delta_seconds();
         if state.timer >= 3.0 {
-            commands.insert_resource(BattleResource(Battle::new(
-                heroes.iter().map(|(h, _)| Player::new(h.clone())).collect(),
-            )));
-            next_state.set(GameState::FightHome);
+            let mut battle =
+                Battle::new(heroes.iter().map(|(h, _)| Player::new(h.clone())).collect());
+            battle.difficulty = difficulty.0;
+            battle.pick_strategies(&Strategy::IN_GAME);
+            humans.first_turn(&battle);
+            humans.select(&mut commands);
+            commands.insert_resource(BattleResource(battle));
+            next_state.set(humans.shop_state());
         }
     }
 }
+
+// online the battle comes from the server with the first shop
+fn wait_for_server(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut inbox: ResMut<NetInbox>,
+) {
+    if let Some(save) = inbox.shop.take() {
+        commands.insert_resource(BattleResource(save.battle()));
+        next_state.set(GameState::FightHome);
+    }
+}
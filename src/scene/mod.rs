@@ -3,7 +3,8 @@ use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
 use fight_arena::FightArena;
 use fight_home::FightHome;
 use game_ended::GameEnded;
-use landing::{HeroSelected, HeroWatch, Landing};
+use hand_off::HandOff;
+use landing::Landing;
 use select_hero::SelectHero;
 use splash::Splash;
 
@@ -17,6 +18,7 @@ pub enum GameState {
     SelectHero,
     Landing,
     FightHome,
+    HandOff,
     FightArena,
     GameEnded,
 }
@@ -32,6 +34,7 @@ impl Plugin for ScenesPlugin {
             SelectHero,
             Landing,
             FightHome,
+            HandOff,
             FightArena,
             GameEnded,
         ));
@@ -96,6 +99,7 @@ pub mod avatars;
 pub mod fight_arena;
 pub mod fight_home;
 pub mod game_ended;
+pub mod hand_off;
 pub mod landing;
 pub mod select_hero;
 pub mod splash;
@@ -3,11 +3,13 @@ use std::error::Error;
 use bevy::{core_pipeline::bloom::BloomSettings, prelude::*};
 
 use crate::{
-    battle_bridge::HeroesResource, component::wheel::Wheel, hero::HeroesRoot,
-    scene::landing::HeroSelected,
+    battle::save::SaveGame,
+    battle_bridge::{BattleResource, DifficultyResource, HeroesResource},
+    component::wheel::Wheel,
+    hero::HeroesRoot,
 };
 
-use super::{landing::HeroWatch, GameState, LocalSchedule, Root, UiRoot};
+use super::{landing::HumanPlayers, GameState, LocalSchedule, Root, UiRoot};
 
 #[derive(Resource, Default)]
 struct State {
@@ -20,7 +22,10 @@ impl Plugin for SelectHero {
     fn build(&self, app: &mut App) {
         app.add_systems(
             LocalSchedule,
-            (init.map(drop), update.run_if(resource_exists::<State>))
+            (
+                init.map(drop),
+                (update, update_seat, update_difficulty).run_if(resource_exists::<State>),
+            )
                 .run_if(in_state(GameState::SelectHero)),
         );
     }
@@ -35,13 +40,22 @@ struct NameNode;
 #[derive(Component)]
 struct StatsNode;
 
+#[derive(Component)]
+struct SeatNode;
+
+#[derive(Component)]
+struct DifficultyNode;
+
 fn init(
     mut commands: Commands,
+    mut humans: ResMut<HumanPlayers>,
     asset_server: Res<AssetServer>,
     root: Query<Entity, Added<Root>>,
 ) -> Result<(), Box<dyn Error>> {
     let root = root.get_single()?;
     commands.insert_resource(State::default());
+    humans.ids.clear();
+    humans.turn = 0;
     commands.entity(root).with_children(|p| {
         p.spawn((
             Camera3dBundle {
@@ -165,6 +179,60 @@ fn init(
                     ),
                 ));
             });
+            if humans.seats > 1 {
+                p.spawn((
+                    SeatNode,
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 35.0,
+                            ..Default::default()
+                        },
+                    )
+                    .with_style(Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(25.0),
+                        left: Val::Px(25.0),
+                        ..Default::default()
+                    }),
+                ));
+            }
+            p.spawn((
+                DifficultyNode,
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 25.0,
+                        ..Default::default()
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(25.0),
+                    right: Val::Px(25.0),
+                    ..Default::default()
+                }),
+            ));
+            if SaveGame::exists() {
+                p.spawn(
+                    TextBundle::from_section(
+                        "C - продолжить сохранённую игру",
+                        TextStyle {
+                            font: font.clone(),
+                            font_size: 25.0,
+                            ..Default::default()
+                        },
+                    )
+                    .with_style(Style {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Px(25.0),
+                        left: Val::Px(25.0),
+                        ..Default::default()
+                    }),
+                );
+            }
         });
     Ok(())
 }
@@ -176,10 +244,27 @@ fn update(
     mut desc_node: Query<&mut Text, (With<DescNode>, Without<NameNode>, Without<StatsNode>)>,
     mut name_node: Query<&mut Text, (Without<DescNode>, With<NameNode>, Without<StatsNode>)>,
     mut stats_node: Query<&mut Text, (Without<DescNode>, Without<NameNode>, With<StatsNode>)>,
+    mut humans: ResMut<HumanPlayers>,
     time: Res<Time>,
     heroes: Res<HeroesResource>,
     wheel: Query<&Wheel>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
 ) {
+    if keyboard_input.just_pressed(KeyCode::KeyC) && SaveGame::exists() {
+        match SaveGame::load() {
+            Ok(save) if !save.humans.is_empty() => {
+                humans.ids = save.humans.clone();
+                humans.turn = save.turn.min(save.humans.len() - 1);
+                humans.select(&mut commands);
+                commands.insert_resource(BattleResource(save.battle()));
+                next_state.set(humans.shop_state());
+                return;
+            }
+            Ok(_) => error!("The saved game has no players"),
+            Err(err) => error!("Failed to load the saved game: {err}"),
+        }
+    }
+
     let wheel = wheel.single();
 
     let (ref selected_hero, _) = heroes[wheel.current()];
@@ -205,14 +290,12 @@ fn update(
         }
     }
 
-    if wheel.selected() {
-        commands.insert_resource(HeroSelected {
-            id: selected_hero.id.to_string(),
-        });
-        commands.insert_resource(HeroWatch {
-            id: selected_hero.id.to_string(),
-        });
-        next_state.set(GameState::Landing);
+    if wheel.selected() && !humans.contains(selected_hero.id) {
+        humans.ids.push(selected_hero.id.to_string());
+        if humans.ids.len() == humans.seats {
+            humans.select(&mut commands);
+            next_state.set(GameState::Landing);
+        }
     }
 
     state.timer += time.delta_seconds();
@@ -220,3 +303,26 @@ fn update(
         // next_state.set(GameState::Landing);
     }
 }
+
+fn update_seat(mut query: Query<&mut Text, With<SeatNode>>, humans: Res<HumanPlayers>) {
+    for mut text in query.iter_mut() {
+        text.sections[0].value = format!(
+            "Игрок {} из {} выбирает героя",
+            humans.ids.len() + 1,
+            humans.seats
+        );
+    }
+}
+
+fn update_difficulty(
+    mut query: Query<&mut Text, With<DifficultyNode>>,
+    mut difficulty: ResMut<DifficultyResource>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyD) {
+        difficulty.0 = difficulty.0.next();
+    }
+    for mut text in query.iter_mut() {
+        text.sections[0].value = format!("D - сложность ботов: {}", difficulty.0.name());
+    }
+}
@@ -0,0 +1,297 @@
+use std::{collections::BTreeMap, error::Error, path::Path};
+
+use rand::random;
+use serde::Serialize;
+
+use crate::battle::{
+    bot::{Difficulty, Strategy},
+    hero,
+    player::Player,
+    ruleset::Ruleset,
+    Battle,
+};
+
+const MAX_ROUNDS: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub games: u32,
+    pub seed: u64,
+    pub format: Format,
+    /// assigned to the heroes in turn
+    pub bots: Vec<Strategy>,
+    pub difficulty: Difficulty,
+    pub ruleset: Ruleset,
+}
+
+impl Config {
+    pub fn from_args() -> Option<Result<Self, Box<dyn Error>>> {
+        let mut args = std::env::args().skip(1);
+        if !args.any(|arg| arg == "--simulate") {
+            return None;
+        }
+        Some(Self::parse(std::env::args().skip(1)))
+    }
+
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut config = Self {
+            games: 100,
+            seed: random(),
+            format: Format::Csv,
+            bots: vec![Strategy::default()],
+            difficulty: Difficulty::default(),
+            ruleset: Ruleset::default(),
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {arg}"));
+            match arg.as_str() {
+                "--simulate" => {}
+                "--games" => config.games = value()?.parse()?,
+                "--seed" => config.seed = value()?.parse()?,
+                "--format" => {
+                    config.format = match value()?.as_str() {
+                        "csv" => Format::Csv,
+                        "json" => Format::Json,
+                        other => return Err(format!("unknown format {other}").into()),
+                    }
+                }
+                "--bots" => {
+                    config.bots = value()?
+                        .split(',')
+                        .map(str::parse)
+                        .collect::<Result<_, _>>()?
+                }
+                "--difficulty" => config.difficulty = value()?.parse()?,
+                "--ruleset" => config.ruleset = Ruleset::load(Path::new(&value()?))?,
+                other => return Err(format!("unknown argument {other}").into()),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PlayerResult {
+    hero: &'static str,
+    placement: u32,
+    rounds: u32,
+    cards: Vec<&'static str>,
+}
+
+fn simulate_game(config: &Config, seed: u64) -> Vec<PlayerResult> {
+    let players = hero::all()
+        .into_iter()
+        .map(|hero| Player::with_ruleset(hero, &config.ruleset))
+        .collect();
+    let mut battle = Battle::with_ruleset(players, seed, config.ruleset.clone());
+    battle.difficulty = config.difficulty;
+    for (player, strategy) in battle.players.iter_mut().zip(config.bots.iter().cycle()) {
+        player.strategy = *strategy;
+    }
+    let mut results: Vec<PlayerResult> = vec![];
+
+    while battle.round <= MAX_ROUNDS {
+        let alive = battle
+            .players
+            .iter()
+            .filter(|p| p.hp > 0)
+            .map(|p| p.hero.id)
+            .collect::<Vec<_>>();
+        if alive.len() <= 1 {
+            break;
+        }
+
+        for id in &alive {
+            battle.ai(id);
+        }
+        battle.round();
+        battle.apply();
+
+        let survivors = battle.players.iter().filter(|p| p.hp > 0).count() as u32;
+        for player in battle
+            .players
+            .iter()
+            .filter(|p| p.hp <= 0 && alive.contains(&p.hero.id))
+        {
+            results.push(PlayerResult {
+                hero: player.hero.id,
+                placement: survivors + 1,
+                rounds: battle.round - 1,
+                cards: player.cards.iter().map(|c| c.id()).collect(),
+            });
+        }
+    }
+
+    let mut survivors = battle
+        .players
+        .iter()
+        .filter(|p| p.hp > 0)
+        .collect::<Vec<_>>();
+    survivors.sort_by_key(|p| std::cmp::Reverse(p.hp));
+    for (i, player) in survivors.into_iter().enumerate() {
+        results.push(PlayerResult {
+            hero: player.hero.id,
+            placement: i as u32 + 1,
+            rounds: battle.round - 1,
+            cards: player.cards.iter().map(|c| c.id()).collect(),
+        });
+    }
+
+    results
+}
+
+#[derive(Debug, Default, Serialize)]
+struct HeroReport {
+    hero: &'static str,
+    games: u32,
+    win_rate: f32,
+    avg_placement: f32,
+    avg_rounds: f32,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct CardReport {
+    card: &'static str,
+    picks: u32,
+    pick_rate: f32,
+    win_rate_picked: f32,
+    win_rate_not_picked: f32,
+    avg_placement_picked: f32,
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    games: u32,
+    seed: u64,
+    heroes: Vec<HeroReport>,
+    cards: Vec<CardReport>,
+}
+
+fn ratio(a: u32, b: u32) -> f32 {
+    if b == 0 {
+        0.0
+    } else {
+        a as f32 / b as f32
+    }
+}
+
+fn report(config: &Config, games: Vec<Vec<PlayerResult>>) -> Report {
+    let mut heroes = BTreeMap::<&'static str, (u32, u32, u32, u32)>::new();
+    let mut cards = BTreeMap::<&'static str, (u32, u32, u32)>::new();
+    let mut total = 0;
+    let mut total_wins = 0;
+
+    for result in games.iter().flatten() {
+        let win = (result.placement == 1) as u32;
+        total += 1;
+        total_wins += win;
+
+        let hero = heroes.entry(result.hero).or_default();
+        hero.0 += 1;
+        hero.1 += win;
+        hero.2 += result.placement;
+        hero.3 += result.rounds;
+
+        for card in &result.cards {
+            let card = cards.entry(card).or_default();
+            card.0 += 1;
+            card.1 += win;
+            card.2 += result.placement;
+        }
+    }
+
+    Report {
+        games: config.games,
+        seed: config.seed,
+        heroes: heroes
+            .into_iter()
+            .map(|(hero, (games, wins, placement, rounds))| HeroReport {
+                hero,
+                games,
+                win_rate: ratio(wins, games),
+                avg_placement: ratio(placement, games),
+                avg_rounds: ratio(rounds, games),
+            })
+            .collect(),
+        cards: cards
+            .into_iter()
+            .map(|(card, (picks, wins, placement))| CardReport {
+                card,
+                picks,
+                pick_rate: ratio(picks, total),
+                win_rate_picked: ratio(wins, picks),
+                win_rate_not_picked: ratio(total_wins - wins, total - picks),
+                avg_placement_picked: ratio(placement, picks),
+            })
+            .collect(),
+    }
+}
+
+fn print_csv(report: &Report) {
+    println!("hero,games,win_rate,avg_placement,avg_rounds");
+    for hero in &report.heroes {
+        println!(
+            "{},{},{:.4},{:.4},{:.4}",
+            hero.hero, hero.games, hero.win_rate, hero.avg_placement, hero.avg_rounds
+        );
+    }
+    println!();
+    println!("card,picks,pick_rate,win_rate_picked,win_rate_not_picked,avg_placement_picked");
+    for card in &report.cards {
+        println!(
+            "{},{},{:.4},{:.4},{:.4},{:.4}",
+            card.card,
+            card.picks,
+            card.pick_rate,
+            card.win_rate_picked,
+            card.win_rate_not_picked,
+            card.avg_placement_picked
+        );
+    }
+}
+
+pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let games = (0..config.games)
+        .map(|game| simulate_game(&config, config.seed.wrapping_add(game as u64)))
+        .collect();
+
+    let report = report(&config, games);
+
+    match config.format {
+        Format::Csv => print_csv(&report),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn simulate_args() {
+    let args = [
+        "--simulate",
+        "--games",
+        "10",
+        "--seed",
+        "3",
+        "--format",
+        "json",
+        "--bots",
+        "greedy,counter",
+        "--difficulty",
+        "hard",
+    ];
+    let config = Config::parse(args.into_iter().map(String::from)).unwrap();
+    assert_eq!(config.games, 10);
+    assert_eq!(config.seed, 3);
+    assert_eq!(config.format, Format::Json);
+    assert_eq!(config.bots, [Strategy::Greedy, Strategy::Counter]);
+    assert_eq!(config.difficulty, Difficulty::Hard);
+    assert!(Config::parse(["--bots", "smart"].into_iter().map(String::from)).is_err());
+    assert!(Config::parse(["--games"].into_iter().map(String::from)).is_err());
+}
@@ -5,12 +5,10 @@ use crate::{
     scene::avatars::AvatarsResource,
 };
 
-use super::{LocalSchedule, DCOLOR};
+use super::LocalSchedule;
 
 pub struct AvatarPlugin;
 
-const SIZE: f32 = 320.0;
-
 impl Plugin for AvatarPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
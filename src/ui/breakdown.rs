@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+
+use crate::{
+    battle::{combat_log, fight::Owner, RoundCapture},
+    battle_bridge::RoundCaptureResource,
+    component::{fight_state::FightState, game_timer::GameTimer},
+    scene::landing::HeroWatch,
+};
+
+use super::{combat_log::source_name, LocalSchedule};
+
+const WIDTH: f32 = 900.0;
+
+pub struct BreakdownPlugin;
+
+impl Plugin for BreakdownPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            LocalSchedule,
+            (
+                init_root,
+                init_column,
+                (show_hide, update_column)
+                    .after(init_column)
+                    .run_if(resource_exists::<RoundCaptureResource>)
+                    .run_if(resource_exists::<FightState>),
+            ),
+        );
+    }
+}
+
+#[derive(Component)]
+pub struct BreakdownRoot;
+
+#[derive(Component)]
+struct BreakdownColumn(Owner);
+
+fn init_root(mut commands: Commands, query: Query<Entity, Added<BreakdownRoot>>) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert(NodeBundle {
+                style: Style {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(50.0),
+                    top: Val::Percent(20.0),
+                    margin: UiRect::left(Val::Px(-WIDTH / 2.0)),
+                    width: Val::Px(WIDTH),
+                    padding: UiRect::all(Val::Px(20.0)),
+                    column_gap: Val::Px(20.0),
+                    ..Default::default()
+                },
+                background_color: Color::BLACK.with_a(0.8).into(),
+                visibility: Visibility::Hidden,
+                ..Default::default()
+            })
+            .with_children(|p| {
+                p.spawn((NodeBundle::default(), BreakdownColumn(Owner::Fighter1)));
+                p.spawn((NodeBundle::default(), BreakdownColumn(Owner::Fighter2)));
+            });
+    }
+}
+
+fn init_column(mut commands: Commands, query: Query<Entity, Added<BreakdownColumn>>) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert(NodeBundle {
+                style: Style {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(50.0),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .with_children(|p| {
+                p.spawn(TextBundle::default());
+            });
+    }
+}
+
+fn show_hide(
+    mut commands: Commands,
+    game_timer: Res<GameTimer>,
+    query: Query<Entity, With<BreakdownRoot>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).insert(match game_timer.red {
+            true => Visibility::Inherited,
+            false => Visibility::Hidden,
+        });
+    }
+}
+
+fn update_column(
+    query: Query<(&BreakdownColumn, &Children)>,
+    mut texts: Query<&mut Text>,
+    capture: Res<RoundCaptureResource>,
+    watch: Res<HeroWatch>,
+    game_timer: Res<GameTimer>,
+    fight_state: Res<FightState>,
+) {
+    if !game_timer.red {
+        return;
+    }
+    let Some(RoundCapture::Fight { fight_capture, .. }) = capture.by_player(&watch.id) else {
+        return;
+    };
+    let breakdown = combat_log::breakdown(&fight_capture.log);
+
+    for (column, children) in query.iter() {
+        let Some(mut text) = children.first().and_then(|c| texts.get_mut(*c).ok()) else {
+            continue;
+        };
+        let fighter = match column.0 {
+            Owner::Fighter1 => &fight_state.fighter1,
+            Owner::Fighter2 => &fight_state.fighter2,
+        };
+        let style = |font_size, color| TextStyle {
+            font_size,
+            color,
+            ..Default::default()
+        };
+        text.sections = vec![TextSection::new(
+            format!("{}\n", fighter.hero.name),
+            style(30.0, Color::YELLOW),
+        )];
+        text.sections
+            .extend(breakdown.iter().filter(|s| s.owner == column.0).map(|s| {
+                let mut line = format!("{}:", source_name(&s.source));
+                if s.hits > 0 || s.evaded > 0 {
+                    line += &format!(" {:.0} damage", s.damage + s.absorbed);
+                    if s.crits > 0 {
+                        line += &format!(", {} crits", s.crits);
+                    }
+                    if s.evaded > 0 {
+                        line += &format!(", {} evaded", s.evaded);
+                    }
+                }
+                if s.healed > 0.0 {
+                    line += &format!(" {:.0} healed", s.healed);
+                }
+                if s.shielded > 0.0 {
+                    line += &format!(" {:.0} shielded", s.shielded);
+                }
+                TextSection::new(line + "\n", style(18.0, Color::WHITE))
+            }));
+    }
+}
@@ -4,7 +4,7 @@ use bevy::{audio::Volume, prelude::*};
 
 use crate::{
     battle::card::{CardBranch, CardOps},
-    battle_bridge::{branch_to_color, BattleResource},
+    battle_bridge::{branch_to_color, BattleResource, Shop},
     component::game_timer::GameTimer,
     scene::{landing::HeroSelected, Root},
     BUTTON_VOLUME,
@@ -40,6 +40,7 @@ impl Plugin for CardsPlugin {
                     init_card_name,
                     init_card_desc,
                     init_card_footer,
+                    update_card_footer.after(init_card_footer),
                     init_cards_controls,
                     init_cards_control,
                     update_cards_control.after(init_cards_control),
@@ -191,7 +192,7 @@ fn init_card_holder(mut commands: Commands, query: Query<Entity, Added<CardHolde
 
 fn update_card_holder(
     mut commands: Commands,
-    mut battle: ResMut<BattleResource>,
+    mut battle: Shop,
     mut query: Query<(
         &mut BackgroundColor,
         &mut ClickState,
@@ -325,7 +326,7 @@ fn init_card_levels(
 ) {
     for (entity, CardLevels(index, lvl_cur, lvl_max)) in query.iter() {
         let gap = 10.0;
-        let level_width = (CARD_WIDTH - gap * (*lvl_max + 1) as f32) as f32 / *lvl_max as f32;
+        let level_width = (CARD_WIDTH - gap * (*lvl_max + 1) as f32) / *lvl_max as f32;
         let level_height = level_width + gap * 2.0;
         commands
             .entity(entity)
@@ -519,6 +520,9 @@ fn init_card_desc(
 #[derive(Component)]
 struct CardFooter(u32);
 
+#[derive(Component)]
+struct CardPrice;
+
 fn init_card_footer(
     mut commands: Commands,
     assets: Res<UiAssets>,
@@ -541,18 +545,44 @@ fn init_card_footer(
                 ..Default::default()
             })
             .with_children(|p| {
-                p.spawn(TextBundle::from_section(
-                    format!("{}$", footer.0),
-                    TextStyle {
-                        font: assets.font_comic.clone_weak(),
-                        font_size: 18.0,
-                        ..Default::default()
-                    },
+                p.spawn((
+                    TextBundle::from_section(
+                        format!("{}$", footer.0),
+                        TextStyle {
+                            font: assets.font_comic.clone_weak(),
+                            font_size: 18.0,
+                            ..Default::default()
+                        },
+                    ),
+                    CardPrice,
                 ));
             });
     }
 }
 
+fn update_card_footer(
+    query: Query<(&CardFooter, &Children)>,
+    mut texts: Query<&mut Text, With<CardPrice>>,
+    battle: Res<BattleResource>,
+    selected: Res<HeroSelected>,
+) {
+    let Some(player) = battle.players.iter().find(|p| p.hero.id == selected.id) else {
+        return;
+    };
+    for (footer, children) in query.iter() {
+        let mut texts = texts.iter_many_mut(children);
+        let price = match player.free_cards {
+            0 => format!("{}$", footer.0),
+            _ => "Free".to_string(),
+        };
+        while let Some(mut text) = texts.fetch_next() {
+            if text.sections[0].value != price {
+                text.sections[0].value.clone_from(&price);
+            }
+        }
+    }
+}
+
 #[derive(Component)]
 struct CardsControls;
 
@@ -649,7 +679,7 @@ fn init_cards_control(
 
 fn update_cards_control(
     mut commands: Commands,
-    mut battle: ResMut<BattleResource>,
+    mut battle: Shop,
     mut game_timer: ResMut<GameTimer>,
     mut query: Query<(
         &CardsControlKind,
@@ -676,7 +706,7 @@ fn update_cards_control(
                 *click_state = ClickState::None;
                 *color = match kind {
                     CardsControlKind::Lock => {
-                        if battle.is_cards_locked() {
+                        if battle.is_cards_locked(&selected.id) {
                             clicked.into()
                         } else {
                             base.into()
@@ -710,7 +740,7 @@ fn update_cards_control(
 
                 *color = match kind {
                     CardsControlKind::Lock => {
-                        if battle.is_cards_locked() {
+                        if battle.is_cards_locked(&selected.id) {
                             hover_clicked.into()
                         } else {
                             hover.into()
@@ -759,8 +789,9 @@ fn update_cards_control(
                     }
                     // CardsControlKind::Random => {}
                     CardsControlKind::Lock => {
-                        let locked = battle.is_cards_locked();
-                        battle.set_cards_locked(!locked);
+                        let id = player.hero.id;
+                        let locked = player.cards_locked;
+                        battle.set_cards_locked(id, !locked);
                     }
                     CardsControlKind::Ready => {
                         if !game_timer.red {
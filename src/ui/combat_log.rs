@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+
+use crate::{
+    battle::{
+        card::{self, set},
+        combat_log::{LogEntry, LogEvent},
+        fight::Owner,
+        item, RoundCapture,
+    },
+    battle_bridge::RoundCaptureResource,
+    component::{fight_state::FightState, game_timer::GameTimer},
+    scene::landing::HeroWatch,
+};
+
+use super::LocalSchedule;
+
+const WIDTH: f32 = 420.0;
+const LINES: usize = 10;
+
+pub struct CombatLogPlugin;
+
+impl Plugin for CombatLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            LocalSchedule,
+            (
+                init_root,
+                update_log
+                    .after(init_root)
+                    .run_if(resource_exists::<RoundCaptureResource>)
+                    .run_if(resource_exists::<FightState>),
+            ),
+        );
+    }
+}
+
+#[derive(Component)]
+pub struct CombatLogRoot;
+
+#[derive(Component)]
+struct CombatLogText;
+
+fn init_root(mut commands: Commands, query: Query<Entity, Added<CombatLogRoot>>) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert(NodeBundle {
+                style: Style {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::FlexEnd,
+                    margin: UiRect::new(Val::Px(20.0), Val::ZERO, Val::ZERO, Val::Auto),
+                    width: Val::Px(WIDTH),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..Default::default()
+                },
+                background_color: Color::BLACK.with_a(0.5).into(),
+                ..Default::default()
+            })
+            .with_children(|p| {
+                p.spawn((TextBundle::default(), CombatLogText));
+            });
+    }
+}
+
+pub fn source_name(id: &str) -> &str {
+    card::defs()
+        .iter()
+        .find(|def| def.id == id)
+        .map(|def| def.name.as_str())
+        .or_else(|| {
+            set::defs()
+                .iter()
+                .find(|def| def.id == id)
+                .map(|def| def.name.as_str())
+        })
+        .or_else(|| item::by_id(id).map(|item| item.name()))
+        .unwrap_or(id)
+}
+
+fn describe(entry: &LogEntry, fight_state: &FightState) -> String {
+    let name = |owner: Owner| match owner {
+        Owner::Fighter1 => fight_state.fighter1.hero.name,
+        Owner::Fighter2 => fight_state.fighter2.hero.name,
+    };
+    let source = format!("{} ({})", name(entry.owner), source_name(&entry.source));
+    let target = name(entry.target);
+    match &entry.event {
+        LogEvent::Damage { evaded: true, .. } => format!("{target} evaded {source}"),
+        LogEvent::Damage {
+            damage_type,
+            amount,
+            dealt,
+            absorbed,
+            crit,
+            ..
+        } => {
+            let mut line =
+                format!("{source} hit {target} for {dealt:.0} ({amount:.0} {damage_type:?})");
+            if *absorbed > 0.0 {
+                line += &format!(", {absorbed:.0} absorbed");
+            }
+            if *crit {
+                line += ", crit!";
+            }
+            line
+        }
+        LogEvent::Heal { amount, healed } => {
+            format!("{source} healed {target} for {healed:.0} of {amount:.0}")
+        }
+        LogEvent::Shield(amount) => format!("{source} shielded {target} for {amount:.0}"),
+        LogEvent::Status(kind) => format!("{source} applied {kind:?} to {target}"),
+        LogEvent::Dispel(dispel) => format!("{source} dispelled {dispel:?} of {target}"),
+        LogEvent::Ulti => format!("{source} used ulti"),
+    }
+}
+
+fn update_log(
+    mut query: Query<&mut Text, With<CombatLogText>>,
+    capture: Res<RoundCaptureResource>,
+    watch: Res<HeroWatch>,
+    game_timer: Res<GameTimer>,
+    fight_state: Res<FightState>,
+) {
+    let log = match capture.by_player(&watch.id) {
+        Some(RoundCapture::Fight { fight_capture, .. }) => &fight_capture.log,
+        _ => return,
+    };
+    let shown = match game_timer.red {
+        true => log.len(),
+        false => log.partition_point(|entry| entry.time <= game_timer.value),
+    };
+
+    for mut text in query.iter_mut() {
+        text.sections = log[shown.saturating_sub(LINES)..shown]
+            .iter()
+            .map(|entry| {
+                TextSection::new(
+                    describe(entry, &fight_state) + "\n",
+                    TextStyle {
+                        font_size: 14.0,
+                        color: match entry.owner {
+                            Owner::Fighter1 => Color::LIME_GREEN,
+                            Owner::Fighter2 => Color::ORANGE_RED,
+                        },
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+    }
+}
@@ -4,6 +4,8 @@ use crate::{hero::HeroId, scene::landing::HeroSelected};
 
 use super::{
     avatar::AvatarRoot,
+    breakdown::BreakdownRoot,
+    combat_log::CombatLogRoot,
     fight_home_layout::RoundsCount,
     game_timer::GameTimerRoot,
     hp_mana_bars::HpManaBarsRoot,
@@ -70,6 +72,7 @@ fn init(
                                 p.spawn((NodeBundle::default(), ScreenBodyTop))
                                     .with_children(|p| {
                                         p.spawn((NodeBundle::default(), PlayersRoot));
+                                        p.spawn((NodeBundle::default(), CombatLogRoot));
                                         p.spawn((
                                             NodeBundle::default(),
                                             StatsRoot,
@@ -88,6 +91,7 @@ fn init(
                                 p.spawn((NodeBundle::default(), AvatarRoot::Right));
                             });
                     });
+                p.spawn((NodeBundle::default(), BreakdownRoot));
             });
     }
 }
@@ -1,11 +1,17 @@
 use bevy::prelude::*;
 
-use crate::{battle_bridge::BattleResource, hero::HeroId, scene::landing::HeroSelected};
+use crate::{
+    battle_bridge::BattleResource,
+    hero::HeroId,
+    scene::landing::{HeroSelected, HeroWatch},
+};
 
 use super::{
     avatar::AvatarRoot,
     cards::CardsRoot,
     game_timer::GameTimerRoot,
+    inventory::InventoryRoot,
+    items::ItemsRoot,
     players::PlayersRoot,
     screen::{
         ScreenBodyBot, ScreenBodyRoot, ScreenBodyTop, ScreenBottom, ScreenFooter, ScreenHeader,
@@ -25,6 +31,12 @@ impl Plugin for FightHomeLayout {
             LocalSchedule,
             update_rounds.run_if(resource_exists::<BattleResource>),
         );
+        app.add_systems(
+            LocalSchedule,
+            update_next_fight
+                .run_if(resource_exists::<BattleResource>)
+                .run_if(resource_exists::<HeroWatch>),
+        );
     }
 }
 
@@ -37,6 +49,46 @@ fn update_rounds(mut query: Query<&mut Text, With<RoundsCount>>, battle: Res<Bat
     }
 }
 
+#[derive(Component)]
+pub struct NextFight;
+
+fn update_next_fight(
+    mut query: Query<&mut Text, With<NextFight>>,
+    battle: Res<BattleResource>,
+    watch: Res<HeroWatch>,
+) {
+    let name = |id: &str| {
+        battle
+            .players
+            .iter()
+            .find(|p| p.hero.id == id)
+            .map_or("?", |p| p.hero.name)
+    };
+    let alive = battle
+        .players
+        .iter()
+        .any(|p| p.hero.id == watch.id && p.hp > 0);
+    let next = if !alive {
+        String::new()
+    } else if let Some(creep) = battle.creep_round() {
+        format!("Next: {}", creep.name)
+    } else if let Some(id) = battle.matchmaking.next_opponent(&watch.id) {
+        let met = battle
+            .matchmaking
+            .history(&watch.id)
+            .filter(|(_, other)| *other == id)
+            .count();
+        format!("Next: {} (met before: {met})", name(id))
+    } else {
+        "Next: ghost".to_string()
+    };
+    for mut text in query.iter_mut() {
+        if text.sections[0].value != next {
+            text.sections[0].value.clone_from(&next);
+        }
+    }
+}
+
 fn init(
     mut commands: Commands,
     selected: Res<HeroSelected>,
@@ -75,6 +127,23 @@ fn init(
                                         ),
                                         RoundsCount,
                                     ));
+                                    p.spawn((
+                                        TextBundle::from_section(
+                                            "",
+                                            TextStyle {
+                                                font_size: 25.0,
+                                                color: Color::WHITE,
+                                                ..Default::default()
+                                            },
+                                        )
+                                        .with_style(
+                                            Style {
+                                                margin: UiRect::horizontal(Val::Px(20.0)),
+                                                ..Default::default()
+                                            },
+                                        ),
+                                        NextFight,
+                                    ));
                                 });
                             });
                         p.spawn((NodeBundle::default(), ScreenBodyRoot))
@@ -82,6 +151,7 @@ fn init(
                                 p.spawn((NodeBundle::default(), ScreenBodyTop))
                                     .with_children(|p| {
                                         p.spawn((NodeBundle::default(), PlayersRoot));
+                                        p.spawn((NodeBundle::default(), InventoryRoot));
                                         p.spawn((
                                             NodeBundle::default(),
                                             StatsRoot,
@@ -93,7 +163,10 @@ fn init(
                                         p.spawn((NodeBundle::default(), CardsRoot));
                                     });
                             });
-                        p.spawn((NodeBundle::default(), ScreenFooter));
+                        p.spawn((NodeBundle::default(), ScreenFooter))
+                            .with_children(|p| {
+                                p.spawn((NodeBundle::default(), ItemsRoot));
+                            });
                         p.spawn((NodeBundle::default(), ScreenBottom))
                             .with_children(|p| {
                                 p.spawn((NodeBundle::default(), AvatarRoot::Left));
@@ -32,6 +32,7 @@ impl Plugin for HpManaBarsPlugin {
 #[derive(Clone, Copy)]
 enum BarKind {
     Hp,
+    Shield,
     Mana,
 }
 
@@ -73,7 +74,7 @@ fn init_root(mut commands: Commands, query: Query<Entity, Added<HpManaBarsRoot>>
                         display: Display::Flex,
                         flex_direction: FlexDirection::Row,
                         width: Val::Percent(35.0),
-                        height: Val::Px(BAR_HEIGHT * 2.0 + 10.0),
+                        height: Val::Px(BAR_HEIGHT * 3.0 + 20.0),
                         column_gap: Val::Px(10.0),
                         ..Default::default()
                     },
@@ -110,6 +111,7 @@ fn init_bars_holder(
             })
             .with_children(|p| {
                 p.spawn((NodeBundle::default(), BarHolder(holder.0, BarKind::Hp)));
+                p.spawn((NodeBundle::default(), BarHolder(holder.0, BarKind::Shield)));
                 p.spawn((NodeBundle::default(), BarHolder(holder.0, BarKind::Mana)));
             });
     }
@@ -188,6 +190,7 @@ fn update_bar_caption(mut query: Query<(&mut Text, &BarCaption)>, fight_state: R
         };
         text.sections[0].value = match kind {
             BarKind::Hp => format!("{:.0} / {:.0}", fighter.hp, fighter.max_hp),
+            BarKind::Shield => format!("{:.0}", fighter.shield_total()),
             BarKind::Mana => format!("{:.0} / {:.0}", fighter.mana, 100),
         };
     }
@@ -208,6 +211,7 @@ fn init_bar(mut commands: Commands, query: Query<(Entity, &Bar), Added<Bar>>) {
             },
             background_color: match bar.1 {
                 BarKind::Hp => Color::LIME_GREEN.into(),
+                BarKind::Shield => Color::SILVER.into(),
                 BarKind::Mana => Color::NAVY.into(),
             },
             ..Default::default()
@@ -223,6 +227,7 @@ fn update_bar(mut query: Query<(&Bar, &mut Style)>, fight_state: Res<FightState>
         };
         let target = match bar.1 {
             BarKind::Hp => 100.0 * fighter.hp / fighter.max_hp,
+            BarKind::Shield => (100.0 * fighter.shield_total() / fighter.max_hp).min(100.0),
             BarKind::Mana => fighter.mana,
         };
         style.width = Val::Percent(match style.width {
@@ -0,0 +1,257 @@
+use bevy::prelude::*;
+
+use crate::{
+    battle::card::{self, CardOps},
+    battle_bridge::{branch_to_color, BattleResource, Shop},
+    scene::landing::{HeroSelected, HeroWatch},
+};
+
+use super::{LocalSchedule, UiAssets};
+
+const WIDTH: f32 = 400.0;
+const PIP_SIZE: f32 = 12.0;
+
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            LocalSchedule,
+            (init_root, update_root.after(init_root), update_sell_button)
+                .run_if(resource_exists::<BattleResource>)
+                .run_if(resource_exists::<HeroSelected>)
+                .run_if(resource_exists::<HeroWatch>),
+        );
+    }
+}
+
+/// Owned cards of the watched hero, the selected hero can sell them
+#[derive(Component)]
+pub struct InventoryRoot;
+
+#[derive(Component, Default, PartialEq)]
+struct Inventory {
+    id: String,
+    cards: Vec<(&'static str, u8)>,
+}
+
+fn init_root(mut commands: Commands, query: Query<Entity, Added<InventoryRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).insert((
+            NodeBundle {
+                style: Style {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    margin: UiRect::new(Val::Auto, Val::Px(10.0), Val::ZERO, Val::Auto),
+                    width: Val::Px(WIDTH),
+                    max_height: Val::Percent(100.0),
+                    row_gap: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    overflow: Overflow::clip_y(),
+                    ..Default::default()
+                },
+                background_color: Color::BLACK.with_a(0.5).into(),
+                ..Default::default()
+            },
+            Inventory::default(),
+        ));
+    }
+}
+
+fn spawn_card(p: &mut ChildBuilder, card: &dyn CardOps, font: &Handle<Font>) {
+    p.spawn(NodeBundle {
+        style: Style {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(4.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .with_children(|p| {
+        p.spawn(
+            TextBundle::from_section(
+                card.name(),
+                TextStyle {
+                    font_size: 18.0,
+                    font: font.clone_weak(),
+                    ..Default::default()
+                },
+            )
+            .with_style(Style {
+                margin: UiRect::right(Val::Auto),
+                ..Default::default()
+            }),
+        );
+        for level in 1..=card.max_level() {
+            p.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(PIP_SIZE),
+                    height: Val::Px(PIP_SIZE),
+                    ..Default::default()
+                },
+                background_color: match level <= card.level() {
+                    true => Color::YELLOW.into(),
+                    false => Color::WHITE.with_a(0.2).into(),
+                },
+                ..Default::default()
+            });
+        }
+    });
+    p.spawn(TextBundle::from_section(
+        card::level_desc(card),
+        TextStyle {
+            font_size: 14.0,
+            color: Color::GRAY,
+            ..Default::default()
+        },
+    ));
+    p.spawn(NodeBundle {
+        style: Style {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(10.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .with_children(|p| {
+        // every level counts once towards each branch of the card
+        for branch in card.branches() {
+            p.spawn(TextBundle::from_section(
+                format!("{branch:?} +{}", card.level()),
+                TextStyle {
+                    font_size: 14.0,
+                    color: branch_to_color(&branch),
+                    ..Default::default()
+                },
+            ));
+        }
+    });
+}
+
+fn update_root(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Inventory)>,
+    battle: Res<BattleResource>,
+    selected: Res<HeroSelected>,
+    watch: Res<HeroWatch>,
+    assets: Res<UiAssets>,
+) {
+    let Some(player) = battle.players.iter().find(|p| p.hero.id == watch.id) else {
+        return;
+    };
+    let current = Inventory {
+        id: watch.id.clone(),
+        cards: player
+            .cards
+            .iter()
+            .map(|card| (card.id(), card.level()))
+            .collect(),
+    };
+    let owned = watch.id == selected.id;
+
+    for (entity, mut inventory) in query.iter_mut() {
+        if *inventory == current {
+            continue;
+        }
+        inventory.id.clone_from(&current.id);
+        inventory.cards.clone_from(&current.cards);
+
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|p| {
+                p.spawn(TextBundle::from_section(
+                    format!("{} cards", player.hero.name),
+                    TextStyle {
+                        font_size: 22.0,
+                        color: Color::YELLOW,
+                        font: assets.font_comic.clone_weak(),
+                    },
+                ));
+                if player.cards.is_empty() {
+                    p.spawn(TextBundle::from_section(
+                        "No cards",
+                        TextStyle {
+                            font_size: 18.0,
+                            color: Color::GRAY,
+                            ..Default::default()
+                        },
+                    ));
+                }
+                for (index, card) in player.cards.iter().enumerate() {
+                    p.spawn(NodeBundle {
+                        style: Style {
+                            display: Display::Flex,
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(10.0),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .with_children(|p| {
+                        p.spawn(NodeBundle {
+                            style: Style {
+                                display: Display::Flex,
+                                flex_direction: FlexDirection::Column,
+                                flex_grow: 1.0,
+                                flex_basis: Val::Px(0.0),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .with_children(|p| spawn_card(p, card.as_ref(), &assets.font_comic));
+                        if !owned {
+                            return;
+                        }
+                        p.spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    padding: UiRect::all(Val::Px(8.0)),
+                                    align_items: AlignItems::Center,
+                                    ..Default::default()
+                                },
+                                background_color: Color::BLACK.with_a(0.5).into(),
+                                ..Default::default()
+                            },
+                            SellButton(index),
+                        ))
+                        .with_children(|p| {
+                            p.spawn(TextBundle::from_section(
+                                format!("Sell +{}$", player.refund(index, &battle.ruleset)),
+                                TextStyle {
+                                    font_size: 18.0,
+                                    color: Color::YELLOW,
+                                    ..Default::default()
+                                },
+                            ));
+                        });
+                    });
+                }
+            });
+    }
+}
+
+#[derive(Component)]
+struct SellButton(usize);
+
+fn update_sell_button(
+    mut battle: Shop,
+    mut query: Query<(&SellButton, &Interaction, &mut BackgroundColor), Changed<Interaction>>,
+    selected: Res<HeroSelected>,
+) {
+    for (button, act, mut color) in query.iter_mut() {
+        *color = match act {
+            Interaction::None => Color::BLACK.with_a(0.5),
+            Interaction::Hovered => (Color::WHITE * 0.2).with_a(0.5),
+            Interaction::Pressed => Color::BLACK.with_a(0.7),
+        }
+        .into();
+        if *act == Interaction::Pressed {
+            battle.sell_card(&selected.id, button.0);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+
+use crate::{
+    battle::item::{self, Item},
+    battle_bridge::{BattleResource, Shop},
+    scene::landing::{HeroSelected, HeroWatch},
+};
+
+use super::{LocalSchedule, UiAssets};
+
+const SLOT_WIDTH: f32 = 200.0;
+
+pub struct ItemsPlugin;
+
+impl Plugin for ItemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            LocalSchedule,
+            (init_root, update_root.after(init_root), update_item_button)
+                .run_if(resource_exists::<BattleResource>)
+                .run_if(resource_exists::<HeroSelected>)
+                .run_if(resource_exists::<HeroWatch>),
+        );
+    }
+}
+
+/// Equipment slots of the watched hero, the selected hero also picks rewards and crafts here
+#[derive(Component)]
+pub struct ItemsRoot;
+
+#[derive(Component, Default, PartialEq)]
+struct Equipment {
+    id: String,
+    items: Vec<&'static str>,
+    choice: Vec<&'static str>,
+}
+
+fn init_root(mut commands: Commands, query: Query<Entity, Added<ItemsRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).insert((
+            NodeBundle {
+                style: Style {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    height: Val::Percent(100.0),
+                    column_gap: Val::Px(10.0),
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    ..Default::default()
+                },
+                background_color: Color::BLACK.with_a(0.5).into(),
+                ..Default::default()
+            },
+            Equipment::default(),
+        ));
+    }
+}
+
+fn spawn_item(p: &mut ChildBuilder, item: Option<Item>, font: &Handle<Font>) {
+    p.spawn(NodeBundle {
+        style: Style {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            width: Val::Px(SLOT_WIDTH),
+            padding: UiRect::horizontal(Val::Px(4.0)),
+            ..Default::default()
+        },
+        background_color: Color::WHITE.with_a(0.1).into(),
+        ..Default::default()
+    })
+    .with_children(|p| {
+        let Some(item) = item else {
+            p.spawn(TextBundle::from_section(
+                "Empty slot",
+                TextStyle {
+                    font_size: 16.0,
+                    color: Color::GRAY,
+                    font: font.clone_weak(),
+                },
+            ));
+            return;
+        };
+        p.spawn(TextBundle::from_section(
+            item.name(),
+            TextStyle {
+                font_size: 16.0,
+                color: match item.is_component() {
+                    true => Color::WHITE,
+                    false => Color::ORANGE,
+                },
+                font: font.clone_weak(),
+            },
+        ));
+        p.spawn(TextBundle::from_section(
+            item.desc(),
+            TextStyle {
+                font_size: 11.0,
+                color: Color::GRAY,
+                ..Default::default()
+            },
+        ));
+    });
+}
+
+fn spawn_button(p: &mut ChildBuilder, label: String, button: ItemButton) {
+    p.spawn((
+        ButtonBundle {
+            style: Style {
+                padding: UiRect::all(Val::Px(8.0)),
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            background_color: Color::BLACK.with_a(0.5).into(),
+            ..Default::default()
+        },
+        button,
+    ))
+    .with_children(|p| {
+        p.spawn(TextBundle::from_section(
+            label,
+            TextStyle {
+                font_size: 16.0,
+                color: Color::YELLOW,
+                ..Default::default()
+            },
+        ));
+    });
+}
+
+fn update_root(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Equipment)>,
+    battle: Res<BattleResource>,
+    selected: Res<HeroSelected>,
+    watch: Res<HeroWatch>,
+    assets: Res<UiAssets>,
+) {
+    let Some(player) = battle.players.iter().find(|p| p.hero.id == watch.id) else {
+        return;
+    };
+    let current = Equipment {
+        id: watch.id.clone(),
+        items: player.items.iter().map(Item::id).collect(),
+        choice: player.item_choice.iter().map(Item::id).collect(),
+    };
+    let owned = watch.id == selected.id;
+    let slots = battle.ruleset.item_slots as usize;
+
+    for (entity, mut equipment) in query.iter_mut() {
+        if *equipment == current {
+            continue;
+        }
+        equipment.id.clone_from(&current.id);
+        equipment.items.clone_from(&current.items);
+        equipment.choice.clone_from(&current.choice);
+
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .with_children(|p| {
+                for slot in 0..slots.max(player.items.len()) {
+                    spawn_item(p, player.items.get(slot).copied(), &assets.font_comic);
+                }
+                if !owned {
+                    return;
+                }
+                for first in 0..player.items.len() {
+                    for second in first + 1..player.items.len() {
+                        if let Some(item) = item::recipe(player.items[first], player.items[second])
+                        {
+                            spawn_button(
+                                p,
+                                format!("Craft {}", item.name()),
+                                ItemButton::Combine(first, second),
+                            );
+                        }
+                    }
+                }
+                if player.item_choice.is_empty() {
+                    return;
+                }
+                p.spawn(TextBundle::from_section(
+                    match player.items.len() < slots {
+                        true => "Pick a reward:",
+                        false => "Slots are full:",
+                    },
+                    TextStyle {
+                        font_size: 18.0,
+                        color: Color::YELLOW,
+                        font: assets.font_comic.clone_weak(),
+                    },
+                ));
+                for (index, item) in player.item_choice.iter().enumerate() {
+                    spawn_button(p, item.name().to_string(), ItemButton::Pick(index));
+                }
+            });
+    }
+}
+
+#[derive(Component)]
+enum ItemButton {
+    Pick(usize),
+    Combine(usize, usize),
+}
+
+fn update_item_button(
+    mut battle: Shop,
+    mut query: Query<(&ItemButton, &Interaction, &mut BackgroundColor), Changed<Interaction>>,
+    selected: Res<HeroSelected>,
+) {
+    for (button, act, mut color) in query.iter_mut() {
+        *color = match act {
+            Interaction::None => Color::BLACK.with_a(0.5),
+            Interaction::Hovered => (Color::WHITE * 0.2).with_a(0.5),
+            Interaction::Pressed => Color::BLACK.with_a(0.7),
+        }
+        .into();
+        if *act == Interaction::Pressed {
+            match *button {
+                ItemButton::Pick(index) => battle.pick_item(&selected.id, index),
+                ItemButton::Combine(first, second) => {
+                    battle.combine_items(&selected.id, first, second)
+                }
+            }
+        }
+    }
+}
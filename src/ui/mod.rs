@@ -1,10 +1,14 @@
 use avatar::AvatarPlugin;
 use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+use breakdown::BreakdownPlugin;
 use cards::CardsPlugin;
+use combat_log::CombatLogPlugin;
 use fight_arena_layout::FightArenaLayout;
 use fight_home_layout::FightHomeLayout;
 use game_timer::GameTimerPlugin;
 use hp_mana_bars::HpManaBarsPlugin;
+use inventory::InventoryPlugin;
+use items::ItemsPlugin;
 use layout::LayoutPlugin;
 use players::PlayersPlugin;
 use screen::ScreenPlugin;
@@ -14,9 +18,13 @@ pub mod fight_arena_layout;
 pub mod fight_home_layout;
 
 mod avatar;
+mod breakdown;
 mod cards;
+mod combat_log;
 mod game_timer;
 mod hp_mana_bars;
+mod inventory;
+mod items;
 mod layout;
 mod players;
 mod screen;
@@ -39,7 +47,11 @@ impl Plugin for UIPlugin {
             StatsPlugin,
             AvatarPlugin,
             CardsPlugin,
+            InventoryPlugin,
+            ItemsPlugin,
             HpManaBarsPlugin,
+            CombatLogPlugin,
+            BreakdownPlugin,
             FightHomeLayout,
             FightArenaLayout,
         ));
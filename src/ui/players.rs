@@ -102,13 +102,7 @@ fn init_players_list(
                             } => vec![*player1, *player2],
                             RoundCapture::Skip(player) => vec![*player],
                         })
-                        .map(|p| {
-                            battle
-                                .players
-                                .iter()
-                                .find(|player| player.hero.id == p)
-                                .unwrap()
-                        })
+                        .filter_map(|p| battle.players.iter().find(|player| player.hero.id == p))
                         .collect()
                 } else {
                     let mut players = battle.players.iter().collect::<Vec<_>>();
@@ -120,7 +114,12 @@ fn init_players_list(
                 for (i, player) in players.iter().enumerate() {
                     let gap = if let Some(round) = &round {
                         match round.by_player(player.hero.id).unwrap() {
-                            RoundCapture::Fight { .. } => i != last && i % 2 == 1,
+                            // creeps and ghosts aren't in the list
+                            RoundCapture::Fight { player2, .. } => {
+                                i != last
+                                    && (*player2 == player.hero.id
+                                        || battle.players.iter().all(|p| p.hero.id != *player2))
+                            }
                             RoundCapture::Skip(_) => player.hp > 0,
                         }
                     } else {
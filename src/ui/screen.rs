@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::{LocalSchedule, DCOLOR};
+use super::LocalSchedule;
 
 pub struct ScreenPlugin;
 
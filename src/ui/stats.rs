@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 
 use crate::{
-    battle::card::CardBranch,
+    battle::card::{
+        set::{self, SetDef},
+        CardBranch,
+    },
     battle_bridge::{branch_to_color, BattleResource},
     hero::HeroId,
 };
@@ -21,6 +24,7 @@ impl Plugin for StatsPlugin {
                 init_stats_root,
                 init_stat_root,
                 update_stat_count.after(init_stat_root),
+                update_set_tiers.after(init_stat_root),
             )
                 .run_if(resource_exists::<BattleResource>),
         );
@@ -70,6 +74,18 @@ fn init_stats_root(mut commands: Commands, query: Query<Entity, Added<StatsRoot>
                 p.spawn((NodeBundle::default(), StatHolder(CardBranch::Crit)));
                 p.spawn(separator.clone());
                 p.spawn((NodeBundle::default(), StatHolder(CardBranch::Evasion)));
+
+                // combos of several branches go under the branches
+                for def in set::defs().iter().filter(|def| def.branches.len() > 1) {
+                    p.spawn(separator.clone());
+                    p.spawn((
+                        TextBundle::default().with_style(Style {
+                            padding: UiRect::horizontal(Val::Px(10.0)),
+                            ..Default::default()
+                        }),
+                        SetTiers(entity, def),
+                    ));
+                }
             });
     }
 }
@@ -114,7 +130,7 @@ fn init_stat_root(
                     style: Style {
                         display: Display::Flex,
                         flex_direction: FlexDirection::Row,
-                        width: Val::Percent(60.0),
+                        width: Val::Percent(40.0),
                         height: Val::Percent(100.0),
                         align_items: AlignItems::Center,
                         justify_content: JustifyContent::Center,
@@ -208,6 +224,12 @@ fn init_stat_root(
                         ));
                     });
                 });
+                for def in set::defs()
+                    .iter()
+                    .filter(|def| def.branches == [stat.0.clone()])
+                {
+                    p.spawn((TextBundle::default(), SetTiers(parent.get(), def)));
+                }
             });
     }
 }
@@ -243,3 +265,52 @@ fn update_stat_count(
         text.sections[0].value = format!("{}", value);
     }
 }
+
+/// Tiers of a set, the reached ones highlighted
+#[derive(Component)]
+pub struct SetTiers(Entity, &'static SetDef);
+
+fn update_set_tiers(
+    mut query: Query<(&SetTiers, &mut Text)>,
+    battle: Res<BattleResource>,
+    hero_ids: Query<&HeroId>,
+) {
+    for (SetTiers(root, def), mut text) in query.iter_mut() {
+        let Ok(hero_id) = hero_ids.get(*root) else {
+            continue;
+        };
+        let Some(player) = battle.players.iter().find(|p| p.hero.id == hero_id.0) else {
+            continue;
+        };
+
+        let tier = def.tier(player);
+        let style = |color| TextStyle {
+            font_size: 16.0,
+            color,
+            ..Default::default()
+        };
+        text.sections.clear();
+        if def.branches.len() > 1 {
+            text.sections.push(TextSection::new(
+                format!("{} ", def.name),
+                style(match tier {
+                    0 => Color::GRAY,
+                    _ => Color::WHITE,
+                }),
+            ));
+            text.sections.extend(def.branches.iter().map(|branch| {
+                TextSection::new(format!("{branch:?} "), style(branch_to_color(branch)))
+            }));
+        }
+        text.sections
+            .extend(def.tiers.iter().enumerate().map(|(i, points)| {
+                TextSection::new(
+                    format!("{points} "),
+                    style(match i < tier {
+                        true => Color::YELLOW,
+                        false => Color::GRAY,
+                    }),
+                )
+            }));
+    }
+}